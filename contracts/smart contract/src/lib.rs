@@ -1,341 +1,9858 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Env, Address, Vec, String, Symbol, symbol_short};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, token, vec, xdr::ToXdr, Env, Address, Bytes, BytesN, Error, IntoVal, Map, TryFromVal, Vec, String, Symbol, Val, symbol_short};
 
 // Storage keys for the contract
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CLOSED: Symbol = symbol_short!("CLOSED");
+const TOKEN: Symbol = symbol_short!("TOKEN");
+const SUBMIT_GATE: Symbol = symbol_short!("GATE");
+const MIN_QUORUM: Symbol = symbol_short!("QUORUM");
+const TTL_BUMP: Symbol = symbol_short!("TTL_BUMP");
+const AUTO_VERIFY: Symbol = symbol_short!("AUTO_VER");
+const COOLDOWN: Symbol = symbol_short!("COOLDOWN");
+const VOTE_COOLDOWN: Symbol = symbol_short!("VOTE_CD");
+const SUPERMAJORITY: Symbol = symbol_short!("SUPERMAJ");
+const CATEGORY_RULES: Symbol = symbol_short!("CAT_RULES");
+const FINALIZE_MODE: Symbol = symbol_short!("FIN_MODE");
+const TIE_POLICY: Symbol = symbol_short!("TIE_POL");
+const TIE_EXTENSION: Symbol = symbol_short!("TIE_EXT");
+const BALANCE_WEIGHTING: Symbol = symbol_short!("BAL_WGHT");
+const CREATOR_REWARD: Symbol = symbol_short!("REWARD");
+const ALLOWED_CATEGORIES: Symbol = symbol_short!("ALLOW_CAT");
+const REP_FLOOR: Symbol = symbol_short!("REP_FLOOR");
+const FLAG_THRESHOLD: Symbol = symbol_short!("FLAG_THR");
+const VOTE_DEPOSIT: Symbol = symbol_short!("DEPOSIT");
+const VOTE_FEE: Symbol = symbol_short!("VOTE_FEE");
+const CALLBACK: Symbol = symbol_short!("CALLBACK");
+const TREASURY: Symbol = symbol_short!("TREASURY");
+const HELD: Symbol = symbol_short!("HELD");
 const FACT_COUNT: Symbol = symbol_short!("FACT_CNT");
+const TOTAL_VOTES: Symbol = symbol_short!("TOT_VOTES");
+const CATEGORY_COUNTS: Symbol = symbol_short!("CAT_CNT");
+const STATUS_COUNTS: Symbol = symbol_short!("STAT_CNT");
+const UNIQUE_VOTERS: Symbol = symbol_short!("VOTER_CNT");
 const FACT_PREFIX: Symbol = symbol_short!("FACT");
+const VOTES_PREFIX: Symbol = symbol_short!("VOTES");
+const STAKES_PREFIX: Symbol = symbol_short!("STAKES");
+const WEIGHTS_PREFIX: Symbol = symbol_short!("WEIGHTS");
+const BALANCES_PREFIX: Symbol = symbol_short!("BALANCES");
+const REASONS_PREFIX: Symbol = symbol_short!("REASONS");
+const TIMELINE_PREFIX: Symbol = symbol_short!("TIMELINE");
+const FLAGGERS_PREFIX: Symbol = symbol_short!("FLAGGERS");
+const DEPOSITS_PREFIX: Symbol = symbol_short!("DEPOSITS");
+const DEPOSIT_PAYERS_PREFIX: Symbol = symbol_short!("DEP_PAYER");
+const COMMITS_PREFIX: Symbol = symbol_short!("COMMITS");
+const HISTORY_PREFIX: Symbol = symbol_short!("HISTORY");
+const COMMENTS_PREFIX: Symbol = symbol_short!("COMMENTS");
+const NULLIFIERS_PREFIX: Symbol = symbol_short!("NULLIFIER");
+const REPUTATION_PREFIX: Symbol = symbol_short!("REP");
+const LAST_ACTIVE_PREFIX: Symbol = symbol_short!("LAST_ACT");
+const SEEN_VOTER_PREFIX: Symbol = symbol_short!("SEEN_VOTR");
+const ABSTAINED_PREFIX: Symbol = symbol_short!("ABSTAINED");
+const DELEGATE_PREFIX: Symbol = symbol_short!("DELEGATE");
+const VOTE_KEY_PREFIX: Symbol = symbol_short!("VOTE_KEY");
+const VOTE_NONCE_PREFIX: Symbol = symbol_short!("VOTE_NONC");
+const BLACKLIST_PREFIX: Symbol = symbol_short!("BLACKLIST");
+const BLACKLIST_COUNT: Symbol = symbol_short!("BL_COUNT");
+const CONTENT_PREFIX: Symbol = symbol_short!("CONTENT");
+const LAST_SUBMIT_PREFIX: Symbol = symbol_short!("LAST_SUB");
+const LAST_VOTE_PREFIX: Symbol = symbol_short!("LAST_VOTE");
+const POLL_COUNT: Symbol = symbol_short!("POLL_CNT");
+const POLL_PREFIX: Symbol = symbol_short!("POLL");
+const POLL_VOTES_PREFIX: Symbol = symbol_short!("POLL_VOTE");
+
+// Minimum lead one side needs over the other for a decisive verdict
+const VERDICT_MARGIN: u32 = 2;
+
+// TTL extension (in ledgers) used until the admin configures one, roughly one day
+const DEFAULT_TTL_BUMP: u32 = 17_280;
+
+// Cap on the extra weight reputation adds to a vote, so no voter dominates a tally
+const MAX_REPUTATION_BONUS: u32 = 4;
+
+// Reputation a voter needs to count as an expert in tier breakdowns, the point
+// where their vote weight bonus is maxed out
+const EXPERT_REPUTATION: i32 = MAX_REPUTATION_BONUS as i32;
+
+// Seconds without earning reputation that cost a voter one point of it
+const REPUTATION_DECAY_PERIOD: u64 = 2_592_000;
+
+// Bounds on each fact's comment thread, keeping its storage entry small
+const MAX_COMMENTS: u32 = 50;
+const MAX_COMMENT_LEN: u32 = 280;
+
+// Prior texts kept per fact; the oldest is dropped once the history is full
+const MAX_HISTORY: u32 = 10;
+
+// Flags that bring a fact to moderators' attention until the admin configures a threshold
+const DEFAULT_FLAG_THRESHOLD: u32 = 5;
+
+// Fixed-point scale for ranking scores, since there are no floats on-chain
+const RANK_SCALE: u64 = 1_000_000;
+
+// Bounds on fact text length (in bytes), keeping facts meaningful and storage cheap
+const MIN_TEXT_LEN: u32 = 10;
+const MAX_TEXT_LEN: u32 = 1_000;
+
+// Layout version written to new facts; bump it whenever `Fact` gains a field
+// and give the field a default in `migrate_stored_fact`
+const FACT_SCHEMA_VERSION: u32 = 4;
+
+// Category given to facts stored before categories existed
+const LEGACY_CATEGORY: Symbol = symbol_short!("general");
+
+/// Lifecycle status of a fact
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FactStatus {
+    Open,
+    Verified,
+    Debunked,
+    Disputed,
+    Expired,
+}
+
+/// Who may trigger finalization of a fact
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FinalizeMode {
+    Anyone,
+    AdminOnly,
+    CreatorOnly,
+}
+
+/// How finalization settles an exact tie between true and false votes
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TiePolicy {
+    Disputed,
+    FavorTrue,
+    FavorFalse,
+    ExtendVoting,
+}
 
 /// Represents a single fact with voting data
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Fact {
     pub id: u32,
+    pub schema_version: u32,
     pub text: String,
+    pub history: Vec<String>,
     pub creator: Address,
+    pub relayer: Option<Address>,
     pub true_votes: u32,
     pub false_votes: u32,
+    pub abstain_votes: u32,
     pub voters: Vec<Address>,
+    pub status: FactStatus,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
+    pub reveal_ends_at: u64,
+    pub expires_at: u64,
+    pub max_votes: u32,
+    pub source: String,
+    pub category: Symbol,
+    pub challengers: Vec<Address>,
+    pub reopened: bool,
+    pub sealed: bool,
+    pub anonymous: bool,
+    pub deleted: bool,
+    pub rewarded: bool,
 }
 
-#[contract]
-pub struct FactVerificationContract;
+impl Fact {
+    /// Whether the fact is currently accepting votes
+    fn is_open(&self) -> bool {
+        !self.deleted && (self.status == FactStatus::Open || self.reopened)
+    }
 
-#[contractimpl]
-impl FactVerificationContract {
-    /// Submit a new fact to the blockchain
-    /// 
-    /// # Arguments
-    /// * env - The Soroban environment
-    /// * creator - The address of the fact creator
-    /// * text - The fact text content
-    /// 
-    /// # Returns
-    /// * u32 - The ID of the newly created fact
-    pub fn submit_fact(env: Env, creator: Address, text: String) -> u32 {
-        // Require authorization from the creator
-        creator.require_auth();
+    /// The fact's true and false votes combined, widened so large stakes on
+    /// both sides can't overflow the sum
+    fn total_votes(&self) -> u64 {
+        self.true_votes as u64 + self.false_votes as u64
+    }
+}
+
+/// Compact view of a fact without its addresses, for clients and
+/// cross-contract calls
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactSummary {
+    pub id: u32,
+    pub text: String,
+    pub true_votes: u32,
+    pub false_votes: u32,
+    pub status: FactStatus,
+}
+
+/// Represents a multiple-choice poll with per-option tallies
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Poll {
+    pub id: u32,
+    pub text: String,
+    pub creator: Address,
+    pub options: Vec<String>,
+    pub tallies: Vec<u32>,
+    pub voters: Vec<Address>,
+}
+
+/// Snapshot of every contract-wide setting, with defaults for unset ones
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    pub admin: Option<Address>,
+    pub paused: bool,
+    pub closed: bool,
+    pub token: Option<Address>,
+    pub gate_token: Option<Address>,
+    pub gate_min_balance: i128,
+    pub submit_cooldown: u64,
+    pub vote_cooldown: u64,
+    pub allowed_categories: Vec<Symbol>,
+    pub min_quorum: u32,
+    pub supermajority: u32,
+    pub category_thresholds: Map<Symbol, (u32, u32)>,
+    pub rep_floor: u32,
+    pub auto_verify_threshold: u32,
+    pub flag_threshold: u32,
+    pub finalize_mode: FinalizeMode,
+    pub tie_policy: TiePolicy,
+    pub tie_extension: u64,
+    pub balance_weighting: bool,
+    pub vote_deposit: i128,
+    pub vote_fee: i128,
+    pub reward_token: Option<Address>,
+    pub reward_amount: i128,
+    pub callback_contract: Option<Address>,
+    pub callback_function: Option<Symbol>,
+    pub callback_tolerates_failure: bool,
+    pub ttl_bump: u32,
+}
+
+/// Aggregate contract metrics, read in one call for off-chain archival
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub total_facts: u32,
+    pub total_votes: u64,
+    pub open: u32,
+    pub verified: u32,
+    pub debunked: u32,
+    pub disputed: u32,
+    pub expired: u32,
+    pub unique_voters: u32,
+}
+
+/// Event published when a new fact is submitted
+#[contractevent(topics = ["submit"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactSubmitted {
+    #[topic]
+    pub creator: Address,
+    pub fact_id: u32,
+    pub text: String,
+}
+
+/// Event published when a vote is cast on a fact
+#[contractevent(topics = ["vote"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCast {
+    #[topic]
+    pub voter: Address,
+    pub fact_id: u32,
+    pub is_true: bool,
+    pub weight: u32,
+}
+
+/// Event published when a fact is settled with a verdict
+#[contractevent(topics = ["final"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactFinalized {
+    #[topic]
+    pub fact_id: u32,
+    pub status: FactStatus,
+}
+
+/// Event published when enough users flag a fact for moderators to review it
+#[contractevent(topics = ["flagged"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactFlagged {
+    #[topic]
+    pub fact_id: u32,
+    pub flag_count: u32,
+}
+
+/// Event published when the admin takes a moderation action
+///
+/// Every admin action shares this schema; contract-wide actions such as
+/// pausing or upgrading use a `fact_id` of zero.
+#[contractevent(topics = ["mod"], data_format = "vec")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Moderation {
+    #[topic]
+    pub action: Symbol,
+    pub admin: Address,
+    pub fact_id: u32,
+}
+
+/// Panic if the admin has permanently closed the contract
+fn require_not_closed(env: &Env) {
+    if env.storage().instance().get(&CLOSED).unwrap_or(false) {
+        panic!("Contract closed");
+    }
+}
+
+/// Load the admin and require its authorization
+///
+/// # Panics
+/// * If the contract has been closed
+/// * If the contract has not been initialized
+fn require_admin(env: &Env) -> Address {
+    require_not_closed(env);
+    let admin: Address = env.storage().instance().get(&ADMIN).expect("Not initialized");
+    admin.require_auth();
+    admin
+}
+
+/// Get the number of ledgers to extend storage TTLs by
+fn ttl_bump(env: &Env) -> u32 {
+    env.storage().instance().get(&TTL_BUMP).unwrap_or(DEFAULT_TTL_BUMP)
+}
+
+/// Extend the TTL of a persistent entry by the configured bump
+fn extend_persistent_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    let bump = ttl_bump(env);
+    env.storage().persistent().extend_ttl(key, bump, bump);
+}
+
+/// Extend the TTL of the contract instance by the configured bump
+fn extend_instance_ttl(env: &Env) {
+    let bump = ttl_bump(env);
+    env.storage().instance().extend_ttl(bump, bump);
+}
+
+/// Panic if the admin has paused or closed the contract
+fn require_not_paused(env: &Env) {
+    require_not_closed(env);
+    if env.storage().instance().get(&PAUSED).unwrap_or(false) {
+        panic!("Contract is paused");
+    }
+}
+
+/// Panic if the creator submitted within the cooldown, otherwise record the submission
+fn enforce_submit_cooldown(env: &Env, creator: &Address) {
+    // Skip the bookkeeping entirely while no cooldown is configured
+    let cooldown: u64 = env.storage().instance().get(&COOLDOWN).unwrap_or(0);
+    if cooldown == 0 {
+        return;
+    }
+
+    let now = env.ledger().timestamp();
+    let last_submit_key = (LAST_SUBMIT_PREFIX, creator.clone());
+    if let Some(last) = env.storage().persistent().get::<_, u64>(&last_submit_key) {
+        if now.saturating_sub(last) < cooldown {
+            panic!("Submitting too frequently");
+        }
+    }
+
+    env.storage().persistent().set(&last_submit_key, &now);
+    extend_persistent_ttl(env, &last_submit_key);
+}
+
+/// Whether `needle` occurs anywhere in `haystack`, compared byte by byte
+fn bytes_contains(haystack: &Bytes, needle: &Bytes) -> bool {
+    if needle.len() > haystack.len() {
+        return false;
+    }
+    (0..=haystack.len() - needle.len())
+        .any(|start| haystack.slice(start..start + needle.len()) == *needle)
+}
+
+/// Add a vote's weight to a counter, panicking rather than wrapping on overflow
+fn add_votes(count: u32, weight: u32) -> u32 {
+    count.checked_add(weight).expect("Vote count overflow")
+}
+
+/// Load the per-voter vote directions recorded for a fact
+fn load_votes(env: &Env, fact_id: u32) -> Map<Address, bool> {
+    env.storage()
+        .persistent()
+        .get(&(VOTES_PREFIX, fact_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Panics unless the fact text is within the length bounds
+fn validate_text(text: &String) {
+    if text.len() < MIN_TEXT_LEN {
+        panic!("Fact text too short");
+    }
+    if text.len() > MAX_TEXT_LEN {
+        panic!("Fact text too long");
+    }
+}
+
+/// Add one to or take one from a category's live fact count
+fn adjust_category_count(env: &Env, category: &Symbol, increment: bool) {
+    let mut counts: Map<Symbol, u32> = env.storage()
+        .instance()
+        .get(&CATEGORY_COUNTS)
+        .unwrap_or(Map::new(env));
+    let count = counts.get(category.clone()).unwrap_or(0);
+    let count = if increment { count + 1 } else { count.saturating_sub(1) };
+    counts.set(category.clone(), count);
+    env.storage().instance().set(&CATEGORY_COUNTS, &counts);
+}
+
+/// Count an address towards the unique voter total the first time it votes
+fn note_voter(env: &Env, voter: &Address) {
+    let seen_key = (SEEN_VOTER_PREFIX, voter.clone());
+    if !env.storage().persistent().has(&seen_key) {
+        env.storage().persistent().set(&seen_key, &true);
+        let count: u32 = env.storage().instance().get(&UNIQUE_VOTERS).unwrap_or(0);
+        env.storage().instance().set(&UNIQUE_VOTERS, &(count + 1));
+    }
+    extend_persistent_ttl(env, &seen_key);
+}
+
+/// Add one to or take one from the live fact count for a status
+fn adjust_status_count(env: &Env, status: FactStatus, increment: bool) {
+    let mut counts: Map<FactStatus, u32> = env.storage()
+        .instance()
+        .get(&STATUS_COUNTS)
+        .unwrap_or(Map::new(env));
+    let count = counts.get(status).unwrap_or(0);
+    let count = if increment { count + 1 } else { count.saturating_sub(1) };
+    counts.set(status, count);
+    env.storage().instance().set(&STATUS_COUNTS, &counts);
+}
+
+/// Add to or take from the stake token held for outstanding deposits and stakes
+fn adjust_held(env: &Env, amount: i128) {
+    let held: i128 = env.storage().instance().get(&HELD).unwrap_or(0);
+    env.storage().instance().set(&HELD, &(held + amount));
+}
+
+/// Move a live fact from its current status count to another
+fn move_status_count(env: &Env, fact: &Fact, status: FactStatus) {
+    adjust_status_count(env, fact.status, false);
+    adjust_status_count(env, status, true);
+}
+
+/// Whether the voter voted within the vote cooldown
+fn in_vote_cooldown(env: &Env, voter: &Address) -> bool {
+    let cooldown: u64 = env.storage().instance().get(&VOTE_COOLDOWN).unwrap_or(0);
+    if cooldown == 0 {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .get::<_, u64>(&(LAST_VOTE_PREFIX, voter.clone()))
+        .is_some_and(|last| env.ledger().timestamp().saturating_sub(last) < cooldown)
+}
+
+/// Panic if the voter voted within the vote cooldown, otherwise record the vote
+fn enforce_vote_cooldown(env: &Env, voter: &Address) {
+    // Skip the bookkeeping entirely while no cooldown is configured
+    let cooldown: u64 = env.storage().instance().get(&VOTE_COOLDOWN).unwrap_or(0);
+    if cooldown == 0 {
+        return;
+    }
+    if in_vote_cooldown(env, voter) {
+        panic!("Voting too frequently");
+    }
+
+    let last_vote_key = (LAST_VOTE_PREFIX, voter.clone());
+    env.storage().persistent().set(&last_vote_key, &env.ledger().timestamp());
+    extend_persistent_ttl(env, &last_vote_key);
+}
+
+/// Per-fact settings that depend on which submit function was used
+struct FactOptions {
+    source: String,
+    reveal_period: Option<u64>,
+    anonymous: bool,
+    relayer: Option<Address>,
+    max_votes: u32,
+}
+
+impl FactOptions {
+    /// Settings for a plain fact without a source
+    fn plain(env: &Env) -> Self {
+        FactOptions { source: String::from_str(env, ""), reveal_period: None, anonymous: false, relayer: None, max_votes: 0 }
+    }
+}
+
+/// Hash a fact's text after lowercasing it and collapsing whitespace, so
+/// trivially different copies of the same claim hash alike
+fn content_hash(env: &Env, text: &String) -> BytesN<32> {
+    let mut normalized = Bytes::new(env);
+    let mut pending_space = false;
+    for byte in text.to_bytes().iter() {
+        if byte.is_ascii_whitespace() {
+            pending_space = !normalized.is_empty();
+            continue;
+        }
+        if pending_space {
+            normalized.push_back(b' ');
+            pending_space = false;
+        }
+        normalized.push_back(byte.to_ascii_lowercase());
+    }
+    env.crypto().sha256(&normalized).to_bytes()
+}
+
+/// Claim a fact text's content hash for a fact
+///
+/// Panics with "Duplicate fact" if another live fact already has the same text.
+fn register_content(env: &Env, text: &String, fact_id: u32) {
+    let content_key = (CONTENT_PREFIX, content_hash(env, text));
+    if env.storage().persistent().has(&content_key) {
+        panic!("Duplicate fact");
+    }
+    env.storage().persistent().set(&content_key, &fact_id);
+    extend_persistent_ttl(env, &content_key);
+}
+
+/// Free a fact text's content hash so the text can be submitted again
+fn release_content(env: &Env, text: &String) {
+    env.storage().persistent().remove(&(CONTENT_PREFIX, content_hash(env, text)));
+}
+
+/// Create and store a new fact, returning its ID
+///
+/// Callers are responsible for authorization and pause checks.
+fn create_fact(
+    env: &Env,
+    creator: Address,
+    text: String,
+    category: Symbol,
+    duration: u64,
+    options: FactOptions,
+) -> u32 {
+    validate_text(&text);
+
+    // Only whitelisted categories are accepted, when a whitelist is set
+    let allowed: Vec<Symbol> = env.storage()
+        .instance()
+        .get(&ALLOWED_CATEGORIES)
+        .unwrap_or(Vec::new(env));
+    if !allowed.is_empty() && !allowed.contains(&category) {
+        panic!("Category not allowed");
+    }
+
+    // Only holders of the gating token may submit, when a gate is set
+    if let Some((token_address, min_balance)) = env.storage()
+        .instance()
+        .get::<_, (Address, i128)>(&SUBMIT_GATE)
+    {
+        if token::Client::new(env, &token_address).balance(&creator) < min_balance {
+            panic!("Insufficient token balance");
+        }
+    }
+
+    // Get the current fact count (or initialize to 0)
+    let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+
+    // Create new fact ID (starting from 1)
+    let new_id = fact_count + 1;
+    register_content(env, &text, new_id);
+
+    // Create the fact struct, with sealed facts revealing after voting closes
+    let voting_ends_at = env.ledger().timestamp().saturating_add(duration);
+    let fact = Fact {
+        id: new_id,
+        schema_version: FACT_SCHEMA_VERSION,
+        text: text.clone(),
+        history: Vec::new(env),
+        creator: creator.clone(),
+        relayer: options.relayer,
+        true_votes: 0,
+        false_votes: 0,
+        abstain_votes: 0,
+        voters: Vec::new(env),
+        status: FactStatus::Open,
+        created_at: env.ledger().timestamp(),
+        voting_ends_at,
+        reveal_ends_at: voting_ends_at.saturating_add(options.reveal_period.unwrap_or(0)),
+        expires_at: 0,
+        max_votes: options.max_votes,
+        source: options.source,
+        category,
+        challengers: Vec::new(env),
+        reopened: false,
+        sealed: options.reveal_period.is_some(),
+        anonymous: options.anonymous,
+        deleted: false,
+        rewarded: false,
+    };
+
+    // Store the fact using a composite key
+    let fact_key = (FACT_PREFIX, new_id);
+    env.storage().persistent().set(&fact_key, &fact);
+
+    // Update the fact count and the fact's category count
+    env.storage().instance().set(&FACT_COUNT, &new_id);
+    adjust_category_count(env, &fact.category, true);
+    adjust_status_count(env, FactStatus::Open, true);
+
+    // Extend the TTL for the fact and counter
+    extend_persistent_ttl(env, &fact_key);
+    extend_instance_ttl(env);
+
+    // Notify off-chain listeners
+    FactSubmitted { creator, fact_id: new_id, text }.publish(env);
+
+    new_id
+}
+
+/// Read one field of a fact stored as a raw field map, if present and well-formed
+fn stored_field<T: TryFromVal<Env, Val>>(env: &Env, raw: &Map<Symbol, Val>, name: &str) -> Option<T> {
+    raw.get(Symbol::new(env, name)).and_then(|val| T::try_from_val(env, &val).ok())
+}
+
+/// Rewrite a fact stored with an older layout in the current one
+///
+/// The fact is read as a raw field map so that missing fields don't fail
+/// deserialization, and each missing field gets the default it would have
+/// had before it existed. Facts stored before deadlines existed stay open
+/// with no deadline. Returns whether the fact was rewritten.
+fn migrate_stored_fact(env: &Env, fact_id: u32) -> bool {
+    let fact_key = (FACT_PREFIX, fact_id);
+    let raw: Map<Symbol, Val> = match env.storage().persistent().get(&fact_key) {
+        Some(raw) => raw,
+        None => return false,
+    };
+    if stored_field::<u32>(env, &raw, "schema_version") == Some(FACT_SCHEMA_VERSION) {
+        return false;
+    }
+
+    let voting_ends_at = stored_field(env, &raw, "voting_ends_at").unwrap_or(u64::MAX);
+    let fact = Fact {
+        id: fact_id,
+        schema_version: FACT_SCHEMA_VERSION,
+        text: stored_field(env, &raw, "text").expect("Malformed fact"),
+        history: stored_field(env, &raw, "history").unwrap_or(Vec::new(env)),
+        creator: stored_field(env, &raw, "creator").expect("Malformed fact"),
+        relayer: stored_field(env, &raw, "relayer").unwrap_or(None),
+        true_votes: stored_field(env, &raw, "true_votes").unwrap_or(0),
+        false_votes: stored_field(env, &raw, "false_votes").unwrap_or(0),
+        abstain_votes: stored_field(env, &raw, "abstain_votes").unwrap_or(0),
+        voters: stored_field(env, &raw, "voters").unwrap_or(Vec::new(env)),
+        status: stored_field(env, &raw, "status").unwrap_or(FactStatus::Open),
+        created_at: stored_field(env, &raw, "created_at").unwrap_or(0),
+        voting_ends_at,
+        reveal_ends_at: stored_field(env, &raw, "reveal_ends_at").unwrap_or(voting_ends_at),
+        expires_at: stored_field(env, &raw, "expires_at").unwrap_or(0),
+        max_votes: stored_field(env, &raw, "max_votes").unwrap_or(0),
+        source: stored_field(env, &raw, "source").unwrap_or(String::from_str(env, "")),
+        category: stored_field(env, &raw, "category").unwrap_or(LEGACY_CATEGORY),
+        challengers: stored_field(env, &raw, "challengers").unwrap_or(Vec::new(env)),
+        reopened: stored_field(env, &raw, "reopened").unwrap_or(false),
+        sealed: stored_field(env, &raw, "sealed").unwrap_or(false),
+        anonymous: stored_field(env, &raw, "anonymous").unwrap_or(false),
+        deleted: stored_field(env, &raw, "deleted").unwrap_or(false),
+        rewarded: stored_field(env, &raw, "rewarded").unwrap_or(false),
+    };
+    env.storage().persistent().set(&fact_key, &fact);
+    extend_persistent_ttl(env, &fact_key);
+
+    true
+}
+
+/// Invoke the registered finalization callback, if any, with `(fact_id, status)`
+///
+/// A failing callback reverts the caller unless it was registered as
+/// tolerated, in which case only the callback's own changes are rolled back.
+fn notify_callback(env: &Env, fact_id: u32, status: FactStatus) {
+    let Some((contract, function, tolerate_failure)) = env.storage()
+        .instance()
+        .get::<_, (Address, Symbol, bool)>(&CALLBACK)
+    else {
+        return;
+    };
+    let args = vec![env, fact_id.into_val(env), status.into_val(env)];
+    if tolerate_failure {
+        let _ = env.try_invoke_contract::<Val, Error>(&contract, &function, args);
+    } else {
+        env.invoke_contract::<Val>(&contract, &function, args);
+    }
+}
+
+/// Apply a verdict to a fact
+///
+/// Voters on the winning side of a decisive verdict gain reputation the
+/// first time the fact is settled, and the creator is paid any configured
+/// reward the first time it's verified. The finalization callback is
+/// notified and vote deposits are returned. The caller saves the fact.
+fn settle_fact(env: &Env, fact: &mut Fact, status: FactStatus, votes: &Map<Address, bool>) {
+    move_status_count(env, fact, status);
+    fact.status = status;
+    fact.reopened = false;
+    FactFinalized { fact_id: fact.id, status }.publish(env);
+    notify_callback(env, fact.id, status);
+    refund_deposits(env, fact.id);
+
+    // Pay the creator from the contract's reward pool while it lasts
+    if status == FactStatus::Verified && !fact.rewarded {
+        if let Some((token_address, amount)) = env.storage()
+            .instance()
+            .get::<_, (Address, i128)>(&CREATOR_REWARD)
+        {
+            let token = token::Client::new(env, &token_address);
+            let pool = env.current_contract_address();
+            if amount > 0 && token.balance(&pool) >= amount {
+                token.transfer(&pool, &fact.creator, &amount);
+                fact.rewarded = true;
+            }
+        }
+    }
+
+    // Reward voters who sided with a decisive verdict, once per fact,
+    // settling any decay so far into the stored reputation
+    if status != FactStatus::Disputed && fact.challengers.is_empty() {
+        let winning_side = status == FactStatus::Verified;
+        let now = env.ledger().timestamp();
+        for (voter, is_true) in votes.iter() {
+            if is_true == winning_side {
+                let score = decayed_reputation(env, &voter);
+                let reputation_key = (REPUTATION_PREFIX, voter.clone());
+                let last_active_key = (LAST_ACTIVE_PREFIX, voter);
+                env.storage().persistent().set(&reputation_key, &(score + 1));
+                env.storage().persistent().set(&last_active_key, &now);
+                extend_persistent_ttl(env, &reputation_key);
+                extend_persistent_ttl(env, &last_active_key);
+            }
+        }
+    }
+}
+
+/// Push a tied fact's voting window, and its reveal window, forward by the
+/// configured tie extension. The caller saves the fact.
+fn extend_tied_vote(env: &Env, fact: &mut Fact) {
+    let extension: u64 = env.storage().instance().get(&TIE_EXTENSION).unwrap_or(0);
+    let now = env.ledger().timestamp();
+    fact.voting_ends_at = fact.voting_ends_at.max(now).saturating_add(extension);
+    fact.reveal_ends_at = fact.reveal_ends_at.max(now).saturating_add(extension);
+}
+
+/// Require whichever authorization the finalize mode calls for
+fn require_finalize_auth(env: &Env, fact: &Fact) {
+    match env.storage().instance().get(&FINALIZE_MODE).unwrap_or(FinalizeMode::Anyone) {
+        FinalizeMode::Anyone => {}
+        FinalizeMode::AdminOnly => {
+            require_admin(env);
+        }
+        FinalizeMode::CreatorOnly => fact.creator.require_auth(),
+    }
+}
+
+/// The quorum and supermajority for facts in a category, falling back to
+/// the global settings when the category has none of its own
+fn category_thresholds(env: &Env, category: &Symbol) -> (u32, u32) {
+    let storage = env.storage().instance();
+    storage
+        .get::<_, Map<Symbol, (u32, u32)>>(&CATEGORY_RULES)
+        .and_then(|rules| rules.get(category.clone()))
+        .unwrap_or_else(|| (
+            storage.get(&MIN_QUORUM).unwrap_or(0),
+            storage.get(&SUPERMAJORITY).unwrap_or(0),
+        ))
+}
+
+/// Work out a fact's verdict along with the votes that count toward it
+///
+/// Votes from blacklisted voters and voters below the reputation floor are
+/// left out, and the fact's category may override the quorum and
+/// supermajority. An exact tie with votes on both sides is settled by the tie
+/// policy, and the verdict is Open when the policy calls for more voting.
+/// Returns None when the remaining votes fall short of the quorum.
+fn tally_verdict(env: &Env, fact: &Fact) -> Option<(FactStatus, Map<Address, bool>)> {
+    // Leave blacklisted voters and voters below the reputation floor
+    // out of the tally
+    let mut votes = load_votes(env, fact.id);
+    let mut true_votes = fact.true_votes as u64;
+    let mut false_votes = fact.false_votes as u64;
+    let blacklisted: u32 = env.storage().instance().get(&BLACKLIST_COUNT).unwrap_or(0);
+    let rep_floor: u32 = env.storage().instance().get(&REP_FLOOR).unwrap_or(0);
+    if blacklisted > 0 || rep_floor > 0 {
+        let weights = load_weights(env, fact.id);
+        for (voter, is_true) in votes.clone().iter() {
+            let below_floor = rep_floor > 0
+                && decayed_reputation(env, &voter) < rep_floor as i32;
+            let excluded = below_floor || (blacklisted > 0
+                && env.storage().persistent().has(&(BLACKLIST_PREFIX, voter.clone())));
+            if excluded {
+                let weight = weights.get(voter.clone()).unwrap_or(1) as u64;
+                if is_true {
+                    true_votes -= weight;
+                } else {
+                    false_votes -= weight;
+                }
+                votes.remove(voter);
+            }
+        }
+    }
+
+    // Enough votes must have been cast for a meaningful verdict
+    let (min_quorum, supermajority) = category_thresholds(env, &fact.category);
+    if true_votes + false_votes + (fact.abstain_votes as u64) < min_quorum as u64 {
+        return None;
+    }
+
+    // Compute the verdict from the tally
+    let tie_policy = env.storage().instance().get(&TIE_POLICY).unwrap_or(TiePolicy::Disputed);
+    let status = if true_votes == false_votes && true_votes > 0 {
+        match tie_policy {
+            TiePolicy::Disputed => FactStatus::Disputed,
+            TiePolicy::FavorTrue => FactStatus::Verified,
+            TiePolicy::FavorFalse => FactStatus::Debunked,
+            TiePolicy::ExtendVoting => FactStatus::Open,
+        }
+    } else if supermajority > 0 {
+        let total = true_votes + false_votes;
+        let required = total * supermajority as u64;
+        if total > 0 && true_votes * 100 >= required {
+            FactStatus::Verified
+        } else if total > 0 && false_votes * 100 >= required {
+            FactStatus::Debunked
+        } else {
+            FactStatus::Disputed
+        }
+    } else if true_votes >= false_votes + VERDICT_MARGIN as u64 {
+        FactStatus::Verified
+    } else if false_votes >= true_votes + VERDICT_MARGIN as u64 {
+        FactStatus::Debunked
+    } else {
+        FactStatus::Disputed
+    };
+    Some((status, votes))
+}
+
+/// Whether the voter has abstained on the fact
+fn has_abstained(env: &Env, fact_id: u32, voter: &Address) -> bool {
+    env.storage().persistent().has(&(ABSTAINED_PREFIX, fact_id, voter.clone()))
+}
+
+/// Panic unless the voter may still vote on the fact
+///
+/// Abstainers aren't in the direction map, so their abstention key is
+/// checked too.
+fn require_can_vote(env: &Env, fact: &Fact, votes: &Map<Address, bool>, voter: &Address, closes_at: u64) {
+    // Settled facts no longer accept votes
+    if !fact.is_open() {
+        panic!("Fact already finalized");
+    }
+
+    // Votes are only accepted until the window closes
+    if env.ledger().timestamp() > closes_at {
+        panic!("Voting period has ended");
+    }
+
+    // Capped facts stop taking votes once their tally reaches the cap
+    if fact.max_votes > 0 && fact.total_votes() >= fact.max_votes as u64 {
+        panic!("Vote cap reached");
+    }
+
+    // Creators cannot attest to their own facts
+    if *voter == fact.creator {
+        panic!("Creator cannot vote on own fact");
+    }
+
+    // Check if voter has already voted
+    if votes.contains_key(voter.clone()) || has_abstained(env, fact.id, voter) {
+        panic!("Already voted on this fact");
+    }
+}
+
+/// Record a vote of the given weight on a fact
+///
+/// Sealed facts only accept votes coming from a reveal, within the reveal
+/// window, and other facts only accept direct votes within the voting window. The fact is verified on the spot once its
+/// true votes reach the auto-verify threshold. The voter's vote cooldown is
+/// enforced, and the payer, the address that authorized the vote, is charged
+/// the vote fee. Callers are responsible for authorization and pause checks.
+///
+/// # Panics
+/// * If the fact doesn't exist
+/// * If the vote path doesn't match the fact's voting mode
+/// * If the fact has already been finalized
+/// * If the voting period has ended
+/// * If the voter is the creator of the fact
+/// * If the voter has already voted on this fact
+/// * If the voter voted within the vote cooldown
+/// * If a vote fee is set but no token has been configured
+fn record_vote(env: &Env, voter: &Address, payer: &Address, fact_id: u32, is_true: bool, weight: u32, revealed: bool) {
+    // Retrieve the fact
+    let fact_key = (FACT_PREFIX, fact_id);
+    let mut fact: Fact = env.storage()
+        .persistent()
+        .get(&fact_key)
+        .expect("Fact not found");
+
+    // Anonymous facts only take plain votes, which don't come through here
+    if fact.anonymous {
+        panic!("Fact uses anonymous voting");
+    }
+
+    // Sealed facts only take revealed votes, once commitments have closed
+    if fact.sealed && !revealed {
+        panic!("Fact uses commit-reveal voting");
+    }
+    if revealed && env.ledger().timestamp() <= fact.voting_ends_at {
+        panic!("Reveal window not open");
+    }
+
+    let votes_key = (VOTES_PREFIX, fact_id);
+    let mut votes = load_votes(env, fact_id);
+    let closes_at = if revealed { fact.reveal_ends_at } else { fact.voting_ends_at };
+    require_can_vote(env, &fact, &votes, voter, closes_at);
+    enforce_vote_cooldown(env, voter);
+    charge_vote_fee(env, payer);
+
+    // Record the vote direction and add voter to the list
+    votes.set(voter.clone(), is_true);
+    fact.voters.push_back(voter.clone());
+
+    // Remember the weight so retracting or changing the vote undoes it exactly
+    let weights_key = (WEIGHTS_PREFIX, fact_id);
+    let mut weights = load_weights(env, fact_id);
+    weights.set(voter.clone(), weight);
+    env.storage().persistent().set(&weights_key, &weights);
+    extend_persistent_ttl(env, &weights_key);
+
+    // Increment the appropriate vote counter by the vote's weight
+    if is_true {
+        fact.true_votes = add_votes(fact.true_votes, weight);
+    } else {
+        fact.false_votes = add_votes(fact.false_votes, weight);
+    }
+
+    // Keep the contract-wide vote total in step
+    let total_votes: u64 = env.storage().instance().get(&TOTAL_VOTES).unwrap_or(0);
+    env.storage().instance().set(&TOTAL_VOTES, &(total_votes + weight as u64));
+    note_voter(env, voter);
+    log_vote_time(env, fact_id, Some(voter.clone()), is_true, weight);
+
+    // Verify automatically once enough true votes have accumulated
+    let auto_verify: u32 = env.storage().instance().get(&AUTO_VERIFY).unwrap_or(0);
+    if auto_verify > 0 && fact.true_votes >= auto_verify {
+        settle_fact(env, &mut fact, FactStatus::Verified, &votes);
+    }
+
+    // Save the updated fact and votes
+    env.storage().persistent().set(&fact_key, &fact);
+    env.storage().persistent().set(&votes_key, &votes);
+
+    // Extend TTL
+    extend_persistent_ttl(env, &fact_key);
+    extend_persistent_ttl(env, &votes_key);
+
+    // Add the fact to the voter's history
+    let history_key = (HISTORY_PREFIX, voter.clone());
+    let mut history: Vec<u32> = env.storage()
+        .persistent()
+        .get(&history_key)
+        .unwrap_or(Vec::new(env));
+    history.push_back(fact_id);
+    env.storage().persistent().set(&history_key, &history);
+    extend_persistent_ttl(env, &history_key);
+
+    // Notify off-chain listeners
+    VoteCast { voter: voter.clone(), fact_id, is_true, weight }.publish(env);
+}
+
+/// Weight of a plain vote: one plus the voter's capped, decayed reputation
+fn reputation_weight(env: &Env, voter: &Address) -> u32 {
+    let bonus = decayed_reputation(env, voter).max(0) as u32;
+    1 + bonus.min(MAX_REPUTATION_BONUS)
+}
+
+/// The voter's token balance as a vote weight, snapshotted the first time
+/// they vote on the fact and reused from then on
+///
+/// SEP-41 tokens don't expose historical balances, so the snapshot is the
+/// balance at that first vote rather than at submission time.
+fn balance_weight(env: &Env, voter: &Address, fact_id: u32) -> u32 {
+    let snapshots_key = (BALANCES_PREFIX, fact_id);
+    let mut snapshots: Map<Address, i128> = env.storage()
+        .persistent()
+        .get(&snapshots_key)
+        .unwrap_or(Map::new(env));
+    let balance = match snapshots.get(voter.clone()) {
+        Some(balance) => balance,
+        None => {
+            let token_address: Address = env.storage()
+                .instance()
+                .get(&TOKEN)
+                .expect("Token not configured");
+            let balance = token::Client::new(env, &token_address).balance(voter);
+            snapshots.set(voter.clone(), balance);
+            env.storage().persistent().set(&snapshots_key, &snapshots);
+            extend_persistent_ttl(env, &snapshots_key);
+            balance
+        }
+    };
+    if balance <= 0 {
+        panic!("No voting power");
+    }
+    balance.min(u32::MAX as i128) as u32
+}
+
+/// The weight of a plain vote, from token balances when the admin has
+/// enabled that and from reputation otherwise
+fn vote_weight(env: &Env, voter: &Address, fact_id: u32) -> u32 {
+    if env.storage().instance().get(&BALANCE_WEIGHTING).unwrap_or(false) {
+        balance_weight(env, voter, fact_id)
+    } else {
+        reputation_weight(env, voter)
+    }
+}
+
+/// Load a fact's vote timeline of (time, voter, direction, weight) entries
+///
+/// Anonymous votes are logged without a voter.
+fn load_timeline(env: &Env, fact_id: u32) -> Vec<(u64, Option<Address>, bool, u32)> {
+    env.storage()
+        .persistent()
+        .get(&(TIMELINE_PREFIX, fact_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append a vote's time, direction and weight to the fact's vote timeline
+fn log_vote_time(env: &Env, fact_id: u32, voter: Option<Address>, is_true: bool, weight: u32) {
+    let timeline_key = (TIMELINE_PREFIX, fact_id);
+    let mut timeline = load_timeline(env, fact_id);
+    timeline.push_back((env.ledger().timestamp(), voter, is_true, weight));
+    env.storage().persistent().set(&timeline_key, &timeline);
+    extend_persistent_ttl(env, &timeline_key);
+}
+
+/// Drop a voter's entry from the fact's vote timeline
+fn unlog_vote_time(env: &Env, fact_id: u32, voter: &Address) {
+    let mut timeline = load_timeline(env, fact_id);
+    let voter = Some(voter.clone());
+    if let Some(index) = timeline.iter().position(|(_, logged, _, _)| logged == voter) {
+        timeline.remove(index as u32);
+        env.storage().persistent().set(&(TIMELINE_PREFIX, fact_id), &timeline);
+    }
+}
+
+/// Load the per-voter reason codes recorded for a fact
+fn load_reasons(env: &Env, fact_id: u32) -> Map<Address, Symbol> {
+    env.storage()
+        .persistent()
+        .get(&(REASONS_PREFIX, fact_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Collect the configured vote fee from a voter into the treasury
+fn charge_vote_fee(env: &Env, voter: &Address) {
+    let fee: i128 = env.storage().instance().get(&VOTE_FEE).unwrap_or(0);
+    if fee == 0 {
+        return;
+    }
+    let token_address: Address = env.storage()
+        .instance()
+        .get(&TOKEN)
+        .expect("Token not configured");
+    token::Client::new(env, &token_address)
+        .transfer(voter, env.current_contract_address(), &fee);
+
+    let treasury: i128 = env.storage().instance().get(&TREASURY).unwrap_or(0);
+    env.storage().instance().set(&TREASURY, &(treasury + fee));
+    extend_instance_ttl(env);
+}
+
+/// Hold the configured vote deposit for a voter until the fact is settled
+///
+/// The payer is usually the voter, but a relayer can put the deposit up for
+/// a signed vote, in which case the refund goes back to the relayer.
+fn take_vote_deposit(env: &Env, payer: &Address, voter: &Address, fact_id: u32) {
+    let amount: i128 = env.storage().instance().get(&VOTE_DEPOSIT).unwrap_or(0);
+    if amount == 0 {
+        return;
+    }
+    let token_address: Address = env.storage()
+        .instance()
+        .get(&TOKEN)
+        .expect("Token not configured");
+    token::Client::new(env, &token_address)
+        .transfer(payer, env.current_contract_address(), &amount);
+    adjust_held(env, amount);
+
+    let deposits_key = (DEPOSITS_PREFIX, fact_id);
+    let mut deposits: Map<Address, i128> = env.storage()
+        .persistent()
+        .get(&deposits_key)
+        .unwrap_or(Map::new(env));
+    deposits.set(voter.clone(), amount);
+    env.storage().persistent().set(&deposits_key, &deposits);
+    extend_persistent_ttl(env, &deposits_key);
+
+    if payer != voter {
+        let payers_key = (DEPOSIT_PAYERS_PREFIX, fact_id);
+        let mut payers = load_deposit_payers(env, fact_id);
+        payers.set(voter.clone(), payer.clone());
+        env.storage().persistent().set(&payers_key, &payers);
+        extend_persistent_ttl(env, &payers_key);
+    }
+}
+
+/// Load who paid the deposits that voters didn't pay themselves
+fn load_deposit_payers(env: &Env, fact_id: u32) -> Map<Address, Address> {
+    env.storage()
+        .persistent()
+        .get(&(DEPOSIT_PAYERS_PREFIX, fact_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Return every vote deposit still held for a fact
+fn refund_deposits(env: &Env, fact_id: u32) {
+    let deposits_key = (DEPOSITS_PREFIX, fact_id);
+    let deposits: Map<Address, i128> = env.storage()
+        .persistent()
+        .get(&deposits_key)
+        .unwrap_or(Map::new(env));
+    if deposits.is_empty() {
+        return;
+    }
+    let token_address: Address = env.storage()
+        .instance()
+        .get(&TOKEN)
+        .expect("Token not configured");
+    let token = token::Client::new(env, &token_address);
+    let payers = load_deposit_payers(env, fact_id);
+    for (voter, amount) in deposits.iter() {
+        let payer = payers.get(voter.clone()).unwrap_or(voter);
+        token.transfer(&env.current_contract_address(), &payer, &amount);
+        adjust_held(env, -amount);
+    }
+    env.storage().persistent().remove(&deposits_key);
+    env.storage().persistent().remove(&(DEPOSIT_PAYERS_PREFIX, fact_id));
+}
+
+/// The nonce the voter's next signed vote must be made over
+fn vote_nonce(env: &Env, voter: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&(VOTE_NONCE_PREFIX, voter.clone()))
+        .unwrap_or(0)
+}
+
+/// Undo every vote on a fact, refunding its stakes and deposits
+///
+/// The fact is dropped from each voter's history, its tally is taken out of
+/// the contract-wide total and zeroed, and the per-fact vote records are
+/// removed. The caller saves the fact.
+fn clear_votes(env: &Env, fact: &mut Fact) {
+    let fact_id = fact.id;
+
+    // Refund any stakes before forgetting them
+    let stakes = load_stakes(env, fact_id);
+    if !stakes.is_empty() {
+        let token_address: Address = env.storage()
+            .instance()
+            .get(&TOKEN)
+            .expect("Token not configured");
+        let token = token::Client::new(env, &token_address);
+        for (voter, stake) in stakes.iter() {
+            token.transfer(&env.current_contract_address(), &voter, &stake);
+            adjust_held(env, -stake);
+        }
+    }
+
+    refund_deposits(env, fact_id);
+
+    // Drop the fact from every voter's history, along with any abstention
+    for voter in fact.voters.iter() {
+        if fact.abstain_votes > 0 {
+            env.storage().persistent().remove(&(ABSTAINED_PREFIX, fact_id, voter.clone()));
+        }
+        let history_key = (HISTORY_PREFIX, voter);
+        let mut history: Vec<u32> = env.storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or(Vec::new(env));
+        if let Some(index) = history.first_index_of(fact_id) {
+            history.remove(index);
+            env.storage().persistent().set(&history_key, &history);
+        }
+    }
+
+    // Take the cleared tally out of the contract-wide total
+    let total_votes: u64 = env.storage().instance().get(&TOTAL_VOTES).unwrap_or(0);
+    env.storage().instance().set(&TOTAL_VOTES, &total_votes.saturating_sub(fact.total_votes()));
+
+    fact.true_votes = 0;
+    fact.false_votes = 0;
+    fact.abstain_votes = 0;
+    fact.voters = Vec::new(env);
+    env.storage().persistent().remove(&(VOTES_PREFIX, fact_id));
+    env.storage().persistent().remove(&(WEIGHTS_PREFIX, fact_id));
+    env.storage().persistent().remove(&(STAKES_PREFIX, fact_id));
+    env.storage().persistent().remove(&(COMMITS_PREFIX, fact_id));
+    env.storage().persistent().remove(&(REASONS_PREFIX, fact_id));
+    env.storage().persistent().remove(&(TIMELINE_PREFIX, fact_id));
+    env.storage().persistent().remove(&(NULLIFIERS_PREFIX, fact_id));
+}
+
+/// An address's reputation after linear decay since it last earned any
+///
+/// Positive reputation loses a point per `REPUTATION_DECAY_PERIOD` down to
+/// zero. Reputation earned before activity was tracked doesn't decay.
+fn decayed_reputation(env: &Env, addr: &Address) -> i32 {
+    let score: i32 = env.storage()
+        .persistent()
+        .get(&(REPUTATION_PREFIX, addr.clone()))
+        .unwrap_or(0);
+    let Some(since) = env.storage().persistent().get::<_, u64>(&(LAST_ACTIVE_PREFIX, addr.clone())) else {
+        return score;
+    };
+    if score <= 0 {
+        return score;
+    }
+    let elapsed = env.ledger().timestamp().saturating_sub(since);
+    score - (elapsed / REPUTATION_DECAY_PERIOD).min(score as u64) as i32
+}
+
+/// Load the vote weights recorded for a fact
+fn load_weights(env: &Env, fact_id: u32) -> Map<Address, u32> {
+    env.storage()
+        .persistent()
+        .get(&(WEIGHTS_PREFIX, fact_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Derive the nullifier standing in for a voter on an anonymous fact
+fn vote_nullifier(env: &Env, voter: &Address, fact_id: u32) -> BytesN<32> {
+    let mut preimage = voter.clone().to_xdr(env);
+    preimage.extend_from_array(&fact_id.to_be_bytes());
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Record a vote on an anonymous fact, keeping only a nullifier for the voter
+///
+/// The voter's vote cooldown is enforced and the voter is charged the vote
+/// fee. Callers are responsible for authorization and pause checks.
+///
+/// # Panics
+/// * If the fact has already been finalized
+/// * If the voting period has ended
+/// * If the voter is the creator of the fact
+/// * If the voter has already voted on this fact
+/// * If the voter voted within the vote cooldown
+/// * If a vote fee is set but no token has been configured
+fn record_anonymous_vote(env: &Env, mut fact: Fact, voter: &Address, is_true: bool) {
+    require_can_vote(env, &fact, &Map::new(env), voter, fact.voting_ends_at);
+    enforce_vote_cooldown(env, voter);
+    charge_vote_fee(env, voter);
+
+    let nullifier = vote_nullifier(env, voter, fact.id);
+    let nullifiers_key = (NULLIFIERS_PREFIX, fact.id);
+    let mut nullifiers: Map<BytesN<32>, bool> = env.storage()
+        .persistent()
+        .get(&nullifiers_key)
+        .unwrap_or(Map::new(env));
+    if nullifiers.contains_key(nullifier.clone()) {
+        panic!("Already voted on this fact");
+    }
+    nullifiers.set(nullifier, true);
+
+    if is_true {
+        fact.true_votes = add_votes(fact.true_votes, 1);
+    } else {
+        fact.false_votes = add_votes(fact.false_votes, 1);
+    }
+
+    // Keep the contract-wide vote total in step
+    let total_votes: u64 = env.storage().instance().get(&TOTAL_VOTES).unwrap_or(0);
+    env.storage().instance().set(&TOTAL_VOTES, &(total_votes + 1));
+    log_vote_time(env, fact.id, None, is_true, 1);
+
+    // Verify automatically once enough true votes have accumulated
+    let auto_verify: u32 = env.storage().instance().get(&AUTO_VERIFY).unwrap_or(0);
+    if auto_verify > 0 && fact.true_votes >= auto_verify {
+        settle_fact(env, &mut fact, FactStatus::Verified, &Map::new(env));
+    }
+
+    // Save the updated fact and nullifiers
+    let fact_key = (FACT_PREFIX, fact.id);
+    env.storage().persistent().set(&fact_key, &fact);
+    env.storage().persistent().set(&nullifiers_key, &nullifiers);
+
+    // Extend TTL
+    extend_persistent_ttl(env, &fact_key);
+    extend_persistent_ttl(env, &nullifiers_key);
+}
+
+/// Whether an address has voted or abstained on a fact, checking anonymous
+/// facts through the voter's nullifier
+fn has_voted_on(env: &Env, fact: &Fact, voter: &Address) -> bool {
+    if fact.anonymous {
+        return env.storage()
+            .persistent()
+            .get::<_, Map<BytesN<32>, bool>>(&(NULLIFIERS_PREFIX, fact.id))
+            .is_some_and(|nullifiers| nullifiers.contains_key(vote_nullifier(env, voter, fact.id)));
+    }
+
+    load_votes(env, fact.id).contains_key(voter.clone()) || has_abstained(env, fact.id, voter)
+}
+
+/// Load the token stakes recorded for a fact
+fn load_stakes(env: &Env, fact_id: u32) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&(STAKES_PREFIX, fact_id))
+        .unwrap_or(Map::new(env))
+}
+
+#[contract]
+pub struct FactVerificationContract;
+
+#[contractimpl]
+impl FactVerificationContract {
+    /// Initialize the contract with an admin
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * admin - The address allowed to perform administrative operations
+    /// 
+    /// # Panics
+    /// * If the contract has already been initialized
+    pub fn initialize(env: Env, admin: Address) {
+        // Require authorization from the admin, so nobody else can claim the role
+        admin.require_auth();
+
+        if env.storage().instance().has(&ADMIN) {
+            panic!("Already initialized");
+        }
+
+        env.storage().instance().set(&ADMIN, &admin);
+        extend_instance_ttl(&env);
+    }
+
+    /// Initialize the contract with an admin unless that was already done
+    /// 
+    /// Lets deployment scripts initialize defensively without handling the
+    /// panic from a repeated `initialize`.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * admin - The address allowed to perform administrative operations
+    /// 
+    /// # Returns
+    /// * bool - True if this call initialized the contract, false if it was
+    ///   already initialized and nothing changed
+    pub fn initialize_if_needed(env: Env, admin: Address) -> bool {
+        // Require authorization from the admin
+        admin.require_auth();
+
+        if env.storage().instance().has(&ADMIN) {
+            return false;
+        }
+
+        env.storage().instance().set(&ADMIN, &admin);
+        extend_instance_ttl(&env);
+        true
+    }
+
+    /// Get the admin address
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * Address - The current admin
+    /// 
+    /// # Panics
+    /// * If the contract has not been initialized
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN).expect("Not initialized")
+    }
+
+    /// Hand the admin role over to a new address (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * new_admin - The address that becomes the admin
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin = require_admin(&env);
+
+        env.storage().instance().set(&ADMIN, &new_admin);
+        extend_instance_ttl(&env);
+
+        // Record the moderation action under the outgoing admin
+        Moderation { action: symbol_short!("set_admin"), admin, fact_id: 0 }.publish(&env);
+    }
+
+    /// Pause or unpause submissions and voting (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * paused - True to halt submissions and voting, false to resume
+    pub fn set_paused(env: Env, paused: bool) {
+        let admin = require_admin(&env);
+
+        env.storage().instance().set(&PAUSED, &paused);
+        extend_instance_ttl(&env);
+
+        // Record the moderation action
+        let action = if paused { symbol_short!("pause") } else { symbol_short!("unpause") };
+        Moderation { action, admin, fact_id: 0 }.publish(&env);
+    }
+
+    /// Permanently close a deprecated deployment (admin only)
+    /// 
+    /// Once closed, every call that changes state panics while reads keep
+    /// working, so storage is left to expire and reclaim its rent. Stakes on
+    /// finalized facts can still be claimed so funds aren't stranded. There
+    /// is no way to reopen a closed contract.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Panics
+    /// * If the contract is already closed
+    pub fn close(env: Env) {
+        let admin = require_admin(&env);
+
+        env.storage().instance().set(&CLOSED, &true);
+        extend_instance_ttl(&env);
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("close"), admin, fact_id: 0 }.publish(&env);
+    }
+
+    /// Check whether the contract has been closed
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * bool - True if the admin has closed the contract
+    pub fn is_closed(env: Env) -> bool {
+        env.storage().instance().get(&CLOSED).unwrap_or(false)
+    }
+
+    /// Check whether the contract is paused
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * bool - True if submissions and voting are halted
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&PAUSED).unwrap_or(false)
+    }
+
+    /// Get every contract-wide setting in one call
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * Config - The current settings, with defaults for any left unset
+    pub fn get_config(env: Env) -> Config {
+        let storage = env.storage().instance();
+        let gate: Option<(Address, i128)> = storage.get(&SUBMIT_GATE);
+        let reward: Option<(Address, i128)> = storage.get(&CREATOR_REWARD);
+        let callback: Option<(Address, Symbol, bool)> = storage.get(&CALLBACK);
+        Config {
+            admin: storage.get(&ADMIN),
+            paused: storage.get(&PAUSED).unwrap_or(false),
+            closed: storage.get(&CLOSED).unwrap_or(false),
+            token: storage.get(&TOKEN),
+            gate_token: gate.clone().map(|(token, _)| token),
+            gate_min_balance: gate.map_or(0, |(_, min_balance)| min_balance),
+            submit_cooldown: storage.get(&COOLDOWN).unwrap_or(0),
+            vote_cooldown: storage.get(&VOTE_COOLDOWN).unwrap_or(0),
+            allowed_categories: storage.get(&ALLOWED_CATEGORIES).unwrap_or(Vec::new(&env)),
+            min_quorum: storage.get(&MIN_QUORUM).unwrap_or(0),
+            supermajority: storage.get(&SUPERMAJORITY).unwrap_or(0),
+            category_thresholds: storage.get(&CATEGORY_RULES).unwrap_or(Map::new(&env)),
+            rep_floor: storage.get(&REP_FLOOR).unwrap_or(0),
+            auto_verify_threshold: storage.get(&AUTO_VERIFY).unwrap_or(0),
+            flag_threshold: storage.get(&FLAG_THRESHOLD).unwrap_or(DEFAULT_FLAG_THRESHOLD),
+            finalize_mode: storage.get(&FINALIZE_MODE).unwrap_or(FinalizeMode::Anyone),
+            tie_policy: storage.get(&TIE_POLICY).unwrap_or(TiePolicy::Disputed),
+            tie_extension: storage.get(&TIE_EXTENSION).unwrap_or(0),
+            balance_weighting: storage.get(&BALANCE_WEIGHTING).unwrap_or(false),
+            vote_deposit: storage.get(&VOTE_DEPOSIT).unwrap_or(0),
+            vote_fee: storage.get(&VOTE_FEE).unwrap_or(0),
+            reward_token: reward.clone().map(|(token, _)| token),
+            reward_amount: reward.map_or(0, |(_, amount)| amount),
+            callback_contract: callback.clone().map(|(contract, _, _)| contract),
+            callback_function: callback.clone().map(|(_, function, _)| function),
+            callback_tolerates_failure: callback.is_some_and(|(_, _, tolerate)| tolerate),
+            ttl_bump: ttl_bump(&env),
+        }
+    }
+
+    /// Set the token used for vote stakes (admin only)
+    /// 
+    /// The token can only change while no deposits or stakes are held and
+    /// the treasury is empty, since those are paid out in the token they
+    /// were collected in.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * token - The token contract address
+    /// 
+    /// # Panics
+    /// * If `token` is the creator reward token
+    /// * If any vote deposit or stake is still held
+    /// * If the treasury hasn't been withdrawn
+    pub fn set_token(env: Env, token: Address) {
+        require_admin(&env);
+
+        let held: i128 = env.storage().instance().get(&HELD).unwrap_or(0);
+        if held > 0 {
+            panic!("Deposits or stakes still held");
+        }
+        let treasury: i128 = env.storage().instance().get(&TREASURY).unwrap_or(0);
+        if treasury > 0 {
+            panic!("Treasury not withdrawn");
+        }
+
+        let reward = env.storage().instance().get::<_, (Address, i128)>(&CREATOR_REWARD);
+        if reward.is_some_and(|(reward_token, _)| reward_token == token) {
+            panic!("Reward token is the stake token");
+        }
+
+        env.storage().instance().set(&TOKEN, &token);
+        extend_instance_ttl(&env);
+    }
+
+    /// Require fact creators to hold a minimum balance of a token (admin only)
+    /// 
+    /// Reads and voting stay ungated.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * token - The gating token contract address
+    /// * min_balance - The balance creators need to submit facts
+    /// 
+    /// # Panics
+    /// * If the minimum balance is negative
+    pub fn set_submission_gate(env: Env, token: Address, min_balance: i128) {
+        require_admin(&env);
+
+        if min_balance < 0 {
+            panic!("Invalid minimum balance");
+        }
+
+        env.storage().instance().set(&SUBMIT_GATE, &(token, min_balance));
+        extend_instance_ttl(&env);
+    }
+
+    /// Switch vote weights between reputation and token balances (admin only)
+    /// 
+    /// With balance weighting on, a vote counts for the voter's balance of
+    /// the configured token, snapshotted on their first vote on each fact so
+    /// tokens acquired later don't add voting power. Votes on anonymous
+    /// facts still count once.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * enabled - True to weight votes by token balance
+    pub fn set_balance_weighting(env: Env, enabled: bool) {
+        require_admin(&env);
+
+        env.storage().instance().set(&BALANCE_WEIGHTING, &enabled);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the reward paid to creators whose facts are verified (admin only)
+    /// 
+    /// Rewards are paid from the contract's own balance of `token`, once per
+    /// fact, and skipped while that balance can't cover them. The reward
+    /// token must differ from the stake token, so rewards can't spend held
+    /// stakes, deposits or fees.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * token - The token contract address rewards are paid in
+    /// * amount - The reward per verified fact, or zero to disable rewards
+    /// 
+    /// # Panics
+    /// * If the amount is negative
+    /// * If `token` is the stake token
+    pub fn set_creator_reward(env: Env, token: Address, amount: i128) {
+        require_admin(&env);
+
+        if amount < 0 {
+            panic!("Invalid reward");
+        }
+        if env.storage().instance().get::<_, Address>(&TOKEN) == Some(token.clone()) {
+            panic!("Reward token is the stake token");
+        }
+
+        env.storage().instance().set(&CREATOR_REWARD, &(token, amount));
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the reputation a voter needs for their votes to count at finalization (admin only)
+    /// 
+    /// Votes from addresses below the floor are still recorded and shown in
+    /// the tally, but don't decide the verdict. Anonymous votes can't be
+    /// traced to an address and always count.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * rep_floor - The minimum reputation, or zero to count every voter
+    pub fn set_rep_floor(env: Env, rep_floor: u32) {
+        require_admin(&env);
+
+        env.storage().instance().set(&REP_FLOOR, &rep_floor);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set how many flags bring a fact to moderators' attention (admin only)
+    /// 
+    /// Until set, `DEFAULT_FLAG_THRESHOLD` flags are needed.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * threshold - The flag count at which `FactFlagged` is published
+    /// 
+    /// # Panics
+    /// * If the threshold is zero
+    pub fn set_flag_threshold(env: Env, threshold: u32) {
+        require_admin(&env);
+
+        if threshold == 0 {
+            panic!("Invalid flag threshold");
+        }
+
+        env.storage().instance().set(&FLAG_THRESHOLD, &threshold);
+        extend_instance_ttl(&env);
+    }
+
+    /// Require a token deposit with each vote to discourage flip-flopping (admin only)
+    /// 
+    /// Deposits are taken in the stake token by `vote`, `vote_with_reason`
+    /// and `reveal_vote`, returned when the fact is settled, deleted or
+    /// expires, and forfeited to the treasury if the vote is retracted. Votes on anonymous facts,
+    /// staked votes and delegated votes take no deposit.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * amount - The deposit per vote, or zero to disable deposits
+    /// 
+    /// # Panics
+    /// * If the amount is negative
+    pub fn set_vote_deposit(env: Env, amount: i128) {
+        require_admin(&env);
+
+        if amount < 0 {
+            panic!("Invalid deposit");
+        }
+
+        env.storage().instance().set(&VOTE_DEPOSIT, &amount);
+        extend_instance_ttl(&env);
+    }
+
+    /// Charge a token fee on every vote, paid into the treasury (admin only)
+    /// 
+    /// Fees are taken in the stake token from whoever authorizes the vote: the
+    /// voter, a delegate voting on their behalf, or the relayer of signed votes.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fee - The fee per vote, or zero for free voting
+    /// 
+    /// # Panics
+    /// * If the fee is negative
+    pub fn set_vote_fee(env: Env, fee: i128) {
+        require_admin(&env);
+
+        if fee < 0 {
+            panic!("Invalid fee");
+        }
+
+        env.storage().instance().set(&VOTE_FEE, &fee);
+        extend_instance_ttl(&env);
+    }
+
+    /// Pay out collected fees and forfeited deposits from the treasury (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * to - The address receiving the tokens
+    /// * amount - How much to withdraw, in the stake token
+    /// 
+    /// # Panics
+    /// * If the amount is not positive
+    /// * If the amount exceeds the treasury balance
+    pub fn withdraw_treasury(env: Env, to: Address, amount: i128) {
+        let admin = require_admin(&env);
+
+        if amount <= 0 {
+            panic!("Invalid amount");
+        }
+        let treasury: i128 = env.storage().instance().get(&TREASURY).unwrap_or(0);
+        if amount > treasury {
+            panic!("Insufficient treasury balance");
+        }
+
+        // Only the tracked treasury is paid out, never held deposits or stakes
+        env.storage().instance().set(&TREASURY, &(treasury - amount));
+        let token_address: Address = env.storage()
+            .instance()
+            .get(&TOKEN)
+            .expect("Token not configured");
+        token::Client::new(&env, &token_address)
+            .transfer(&env.current_contract_address(), &to, &amount);
+        extend_instance_ttl(&env);
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("withdraw"), admin, fact_id: 0 }.publish(&env);
+    }
+
+    /// Set how finalization settles an exact tie (admin only)
+    /// 
+    /// A tie needs at least one vote on each side; a fact with no votes is
+    /// always Disputed. Until set, ties are Disputed.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * policy - The tie policy
+    /// * extension - How many seconds `ExtendVoting` adds to the voting window
+    /// 
+    /// # Panics
+    /// * If the policy is `ExtendVoting` and the extension is zero
+    pub fn set_tie_policy(env: Env, policy: TiePolicy, extension: u64) {
+        require_admin(&env);
+
+        if policy == TiePolicy::ExtendVoting && extension == 0 {
+            panic!("Invalid tie extension");
+        }
+
+        env.storage().instance().set(&TIE_POLICY, &policy);
+        env.storage().instance().set(&TIE_EXTENSION, &extension);
+        extend_instance_ttl(&env);
+    }
+
+    /// Register a contract to notify whenever a fact is settled (admin only)
+    /// 
+    /// The function is invoked with `(fact_id: u32, status: FactStatus)`
+    /// from `finalize_fact`, `finalize_due` and automatic verification.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * contract - The contract to call
+    /// * function - The name of the function to call
+    /// * tolerate_failure - Whether a failing callback should be ignored
+    ///   instead of reverting the finalization
+    pub fn set_finalize_callback(env: Env, contract: Address, function: Symbol, tolerate_failure: bool) {
+        require_admin(&env);
+
+        env.storage().instance().set(&CALLBACK, &(contract, function, tolerate_failure));
+        extend_instance_ttl(&env);
+    }
+
+    /// Stop notifying the finalization callback (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    pub fn clear_finalize_callback(env: Env) {
+        require_admin(&env);
+
+        env.storage().instance().remove(&CALLBACK);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set who may finalize facts (admin only)
+    /// 
+    /// Until set, anyone may finalize.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * mode - Whether anyone, only the admin or only each fact's creator may finalize
+    pub fn set_finalize_mode(env: Env, mode: FinalizeMode) {
+        require_admin(&env);
+
+        env.storage().instance().set(&FINALIZE_MODE, &mode);
+        extend_instance_ttl(&env);
+    }
+
+    /// Restrict submissions to a set of categories (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * cats - The categories facts may be filed under, or an empty list to allow any
+    pub fn set_allowed_categories(env: Env, cats: Vec<Symbol>) {
+        require_admin(&env);
+
+        env.storage().instance().set(&ALLOWED_CATEGORIES, &cats);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set how long a creator must wait between submissions (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * cooldown - The minimum gap in seconds, or zero to disable the limit
+    pub fn set_submit_cooldown(env: Env, cooldown: u64) {
+        require_admin(&env);
+
+        env.storage().instance().set(&COOLDOWN, &cooldown);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set how long an address must wait between votes (admin only)
+    /// 
+    /// The cooldown applies across all facts to every vote the address casts
+    /// or has cast for it, whether directly, by a delegate, through a reveal
+    /// or as a signed vote.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * cooldown - The minimum gap in seconds, or zero to disable throttling
+    pub fn set_vote_cooldown(env: Env, cooldown: u64) {
+        require_admin(&env);
+
+        env.storage().instance().set(&VOTE_COOLDOWN, &cooldown);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the vote percentage a side needs to win finalization (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * threshold - The winning percentage (51-100), or zero to use the
+    ///   verdict margin instead
+    /// 
+    /// # Panics
+    /// * If the threshold is non-zero and not above 50 or above 100
+    pub fn set_supermajority(env: Env, threshold: u32) {
+        require_admin(&env);
+
+        if threshold != 0 && (threshold <= 50 || threshold > 100) {
+            panic!("Invalid supermajority");
+        }
+
+        env.storage().instance().set(&SUPERMAJORITY, &threshold);
+        extend_instance_ttl(&env);
+    }
+
+    /// Give a category its own quorum and supermajority (admin only)
+    /// 
+    /// Facts in the category are finalized with these in place of the
+    /// global `set_min_quorum` and `set_supermajority` settings.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * category - The category the thresholds apply to
+    /// * min_quorum - The minimum `true_votes + false_votes + abstain_votes`
+    ///   for finalization
+    /// * supermajority - The winning percentage (51-100), or zero to use the
+    ///   verdict margin instead
+    /// 
+    /// # Panics
+    /// * If the supermajority is non-zero and not above 50 or above 100
+    pub fn set_category_thresholds(env: Env, category: Symbol, min_quorum: u32, supermajority: u32) {
+        require_admin(&env);
+
+        if supermajority != 0 && (supermajority <= 50 || supermajority > 100) {
+            panic!("Invalid supermajority");
+        }
+
+        let mut rules: Map<Symbol, (u32, u32)> = env.storage()
+            .instance()
+            .get(&CATEGORY_RULES)
+            .unwrap_or(Map::new(&env));
+        rules.set(category, (min_quorum, supermajority));
+        env.storage().instance().set(&CATEGORY_RULES, &rules);
+        extend_instance_ttl(&env);
+    }
+
+    /// Return a category to the global quorum and supermajority (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * category - The category whose own thresholds are dropped
+    pub fn clear_category_thresholds(env: Env, category: Symbol) {
+        require_admin(&env);
+
+        let mut rules: Map<Symbol, (u32, u32)> = env.storage()
+            .instance()
+            .get(&CATEGORY_RULES)
+            .unwrap_or(Map::new(&env));
+        rules.remove(category);
+        env.storage().instance().set(&CATEGORY_RULES, &rules);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the minimum number of votes needed to finalize a fact (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * min_quorum - The minimum `true_votes + false_votes + abstain_votes`
+    ///   for finalization
+    pub fn set_min_quorum(env: Env, min_quorum: u32) {
+        require_admin(&env);
+
+        env.storage().instance().set(&MIN_QUORUM, &min_quorum);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set how many ledgers storage TTLs are extended by (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * ttl_bump - The TTL extension in ledgers
+    /// 
+    /// # Panics
+    /// * If the bump is zero or exceeds the network's maximum TTL
+    pub fn set_ttl_bump(env: Env, ttl_bump: u32) {
+        require_admin(&env);
+
+        if ttl_bump == 0 || ttl_bump > env.storage().max_ttl() {
+            panic!("Invalid TTL bump");
+        }
+
+        env.storage().instance().set(&TTL_BUMP, &ttl_bump);
+        extend_instance_ttl(&env);
+    }
+
+    /// Set the true-vote count at which facts are verified automatically (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * threshold - The number of true votes that verifies a fact, or 0 to disable
+    pub fn set_auto_verify_threshold(env: Env, threshold: u32) {
+        require_admin(&env);
+
+        env.storage().instance().set(&AUTO_VERIFY, &threshold);
+        extend_instance_ttl(&env);
+    }
+
+    /// Replace the contract's code while keeping its storage (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * new_wasm_hash - The hash of an already uploaded contract wasm
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin = require_admin(&env);
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("upgrade"), admin, fact_id: 0 }.publish(&env);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Rewrite a fact stored by an older version of the contract (admin only)
+    /// 
+    /// Each fact records the `schema_version` of the layout it was written
+    /// with. Facts from older layouts can't be read as a `Fact` until they
+    /// are migrated, which fills the fields they lack with defaults.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to migrate
+    /// 
+    /// # Returns
+    /// * bool - True if the fact was rewritten, false if it was already current
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    /// * If the stored fact lacks its text or creator
+    pub fn migrate_fact(env: Env, fact_id: u32) -> bool {
+        require_admin(&env);
+
+        if !env.storage().persistent().has(&(FACT_PREFIX, fact_id)) {
+            panic!("Fact not found");
+        }
+
+        migrate_stored_fact(&env, fact_id)
+    }
+
+    /// Rewrite every fact stored by an older version of the contract (admin only)
+    /// 
+    /// Scans every fact ID, so very large deployments may need to call
+    /// `migrate_fact` in smaller batches instead.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * u32 - The number of facts that were rewritten
+    pub fn migrate_all(env: Env) -> u32 {
+        require_admin(&env);
+
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut migrated = 0;
+        for id in 1..=fact_count {
+            if migrate_stored_fact(&env, id) {
+                migrated += 1;
+            }
+        }
+
+        migrated
+    }
+
+    /// Submit a new fact to the blockchain
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * text - The fact text content
+    /// * category - The topic the fact belongs to (e.g. science, politics)
+    /// * duration - How long (in seconds) the fact accepts votes
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created fact
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the creator submitted within the cooldown
+    /// * If a live fact already has the same text, ignoring case and spacing
+    /// * If the admin has whitelisted categories and this isn't one of them
+    pub fn submit_fact(
+        env: Env,
+        creator: Address,
+        text: String,
+        category: Symbol,
+        duration: u64,
+    ) -> u32 {
+        // Require authorization from the creator
+        creator.require_auth();
+        require_not_paused(&env);
+        enforce_submit_cooldown(&env, &creator);
+
+        create_fact(&env, creator, text, category, duration, FactOptions::plain(&env))
+    }
+
+    /// Submit a new fact along with a source URL or citation
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * text - The fact text content
+    /// * category - The topic the fact belongs to (e.g. science, politics)
+    /// * duration - How long (in seconds) the fact accepts votes
+    /// * source - A URL or citation backing the fact
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created fact
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    pub fn submit_fact_with_source(
+        env: Env,
+        creator: Address,
+        text: String,
+        category: Symbol,
+        duration: u64,
+        source: String,
+    ) -> u32 {
+        // Require authorization from the creator
+        creator.require_auth();
+        require_not_paused(&env);
+        enforce_submit_cooldown(&env, &creator);
+
+        create_fact(&env, creator, text, category, duration, FactOptions { source, ..FactOptions::plain(&env) })
+    }
+
+    /// Submit several facts in a single transaction
+    /// 
+    /// While a submission cooldown is configured a batch may hold only one
+    /// fact, so batching can't be used to get around the cooldown.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * texts - The text content of each fact
+    /// * category - The topic shared by all facts in the batch
+    /// * duration - How long (in seconds) each fact accepts votes
+    /// 
+    /// # Returns
+    /// * Vec<u32> - The IDs of the newly created facts, in order
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If a cooldown is configured and the batch holds more than one fact
+    pub fn submit_facts_batch(
+        env: Env,
+        creator: Address,
+        texts: Vec<String>,
+        category: Symbol,
+        duration: u64,
+    ) -> Vec<u32> {
+        // Require authorization from the creator once for the whole batch
+        creator.require_auth();
+        require_not_paused(&env);
+        let cooldown: u64 = env.storage().instance().get(&COOLDOWN).unwrap_or(0);
+        if cooldown > 0 && texts.len() > 1 {
+            panic!("Batches are limited to one fact during a cooldown");
+        }
+        enforce_submit_cooldown(&env, &creator);
+
+        let mut ids = Vec::new(&env);
+        for text in texts.iter() {
+            let id = create_fact(&env, creator.clone(), text, category.clone(), duration, FactOptions::plain(&env));
+            ids.push_back(id);
+        }
+
+        ids
+    }
+
+    /// Submit a new fact that only accepts commit-reveal votes
+    /// 
+    /// Voters commit to a hidden choice with `commit_vote` while voting is
+    /// open, then disclose it with `reveal_vote` during the reveal window that
+    /// follows, so tallies only grow as votes are revealed. Commitments that
+    /// are never revealed don't count. Plain `vote` calls are rejected for
+    /// such facts.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * text - The fact text content
+    /// * category - The topic the fact belongs to (e.g. science, politics)
+    /// * duration - How long (in seconds) the fact accepts commitments
+    /// * reveal_period - How long (in seconds) after that votes can be revealed
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created fact
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    pub fn submit_sealed_fact(
+        env: Env,
+        creator: Address,
+        text: String,
+        category: Symbol,
+        duration: u64,
+        reveal_period: u64,
+    ) -> u32 {
+        // Require authorization from the creator
+        creator.require_auth();
+        require_not_paused(&env);
+        enforce_submit_cooldown(&env, &creator);
+
+        let options = FactOptions { reveal_period: Some(reveal_period), ..FactOptions::plain(&env) };
+        create_fact(&env, creator, text, category, duration, options)
+    }
+
+    /// Submit a new fact whose votes aren't linked to voter addresses
+    /// 
+    /// Votes on such facts only bump the counters and store a nullifier,
+    /// `sha256(voter || fact_id)`, to block double voting. Voters aren't added
+    /// to the fact's `voters` list, their history or the vote events, and
+    /// every vote counts once regardless of reputation. Anonymous votes can't
+    /// be retracted, changed, staked or delegated, and don't earn reputation.
+    /// 
+    /// This only keeps the link out of contract storage: the voting
+    /// transaction itself still names the voter, and because nullifiers are
+    /// deterministic anyone can check whether a known address has voted.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * text - The fact text content
+    /// * category - The topic the fact belongs to (e.g. science, politics)
+    /// * duration - How long (in seconds) the fact accepts votes
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created fact
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    pub fn submit_anonymous_fact(
+        env: Env,
+        creator: Address,
+        text: String,
+        category: Symbol,
+        duration: u64,
+    ) -> u32 {
+        // Require authorization from the creator
+        creator.require_auth();
+        require_not_paused(&env);
+        enforce_submit_cooldown(&env, &creator);
+
+        let options = FactOptions { anonymous: true, ..FactOptions::plain(&env) };
+        create_fact(&env, creator, text, category, duration, options)
+    }
+
+    /// Submit a new fact that stops taking votes once its tally reaches a cap
+    /// 
+    /// The cap applies to the combined true and false tally, so weighted
+    /// votes use it up faster.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * text - The fact text content
+    /// * category - The topic the fact belongs to (e.g. science, politics)
+    /// * duration - How long (in seconds) the fact accepts votes
+    /// * max_votes - The tally at which voting stops, or zero for no cap
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created fact
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the creator submitted within the cooldown
+    pub fn submit_capped_fact(
+        env: Env,
+        creator: Address,
+        text: String,
+        category: Symbol,
+        duration: u64,
+        max_votes: u32,
+    ) -> u32 {
+        // Require authorization from the creator
+        creator.require_auth();
+        require_not_paused(&env);
+        enforce_submit_cooldown(&env, &creator);
+
+        let options = FactOptions { max_votes, ..FactOptions::plain(&env) };
+        create_fact(&env, creator, text, category, duration, options)
+    }
+
+    /// Submit a new fact through a relayer that pays the transaction fees
+    /// 
+    /// The fact belongs to `creator` as usual; the relayer is only recorded on
+    /// the fact for fee accounting.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the fact creator
+    /// * relayer - The address submitting the transaction on the creator's behalf
+    /// * text - The fact text content
+    /// * category - The topic the fact belongs to (e.g. science, politics)
+    /// * duration - How long (in seconds) the fact accepts votes
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created fact
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the creator submitted within the cooldown
+    pub fn submit_fact_for(
+        env: Env,
+        creator: Address,
+        relayer: Address,
+        text: String,
+        category: Symbol,
+        duration: u64,
+    ) -> u32 {
+        // Require authorization from both the creator and the relayer
+        creator.require_auth();
+        relayer.require_auth();
+        require_not_paused(&env);
+        enforce_submit_cooldown(&env, &creator);
+
+        let options = FactOptions { relayer: Some(relayer), ..FactOptions::plain(&env) };
+        create_fact(&env, creator, text, category, duration, options)
+    }
+
+    /// Vote on a fact (true or false)
+    /// 
+    /// The vote counts for one plus the voter's reputation, with the bonus
+    /// capped at `MAX_REPUTATION_BONUS`, or for the voter's token balance when
+    /// balance weighting is on (see `set_balance_weighting`). Votes on
+    /// anonymous facts always count once (see `submit_anonymous_fact`).
+    /// Any vote fee is paid into the treasury (see `set_vote_fee`).
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the fact to vote on
+    /// * is_true - True for "true" vote, false for "false" vote
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the voter voted within the vote cooldown
+    /// * If a vote fee is set but no token has been configured
+    /// * If the fact doesn't exist
+    /// * If the fact has already been finalized
+    /// * If the voting period has ended
+    /// * If the voter is the creator of the fact
+    /// * If the voter has already voted on this fact
+    pub fn vote(env: Env, voter: Address, fact_id: u32, is_true: bool) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        // Anonymous facts take the vote without recording who cast it
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&(FACT_PREFIX, fact_id))
+            .expect("Fact not found");
+        if fact.anonymous {
+            record_anonymous_vote(&env, fact, &voter, is_true);
+            return;
+        }
+
+        let weight = vote_weight(&env, &voter, fact_id);
+        take_vote_deposit(&env, &voter, &voter, fact_id);
+        record_vote(&env, &voter, &voter, fact_id, is_true, weight, false);
+    }
+
+    /// Vote on a fact with a short code explaining the choice
+    /// 
+    /// The reason (e.g. `outdated`, `misleading`, `fabricated`) is stored
+    /// alongside the vote and dropped if the vote is retracted. Plain `vote`
+    /// calls carry no reason.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the fact to vote on
+    /// * is_true - True for "true" vote, false for "false" vote
+    /// * reason - The reason code for the vote
+    /// 
+    /// # Panics
+    /// * If the fact uses anonymous voting
+    /// * If the vote itself is rejected (see `vote`)
+    pub fn vote_with_reason(env: Env, voter: Address, fact_id: u32, is_true: bool, reason: Symbol) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        let weight = vote_weight(&env, &voter, fact_id);
+        take_vote_deposit(&env, &voter, &voter, fact_id);
+        record_vote(&env, &voter, &voter, fact_id, is_true, weight, false);
+
+        // Remember why the voter chose this way
+        let reasons_key = (REASONS_PREFIX, fact_id);
+        let mut reasons = load_reasons(&env, fact_id);
+        reasons.set(voter, reason);
+        env.storage().persistent().set(&reasons_key, &reasons);
+        extend_persistent_ttl(&env, &reasons_key);
+    }
+
+    /// Abstain on a fact, counting towards quorum without taking a side
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the fact to abstain on
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the voter voted within the vote cooldown
+    /// * If the fact doesn't exist or uses anonymous voting
+    /// * If the fact has already been finalized
+    /// * If the voting period has ended
+    /// * If the voter is the creator of the fact
+    /// * If the voter has already voted on this fact
+    pub fn vote_abstain(env: Env, voter: Address, fact_id: u32) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+        enforce_vote_cooldown(&env, &voter);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+        if fact.anonymous {
+            panic!("Fact uses anonymous voting");
+        }
+        require_can_vote(&env, &fact, &load_votes(&env, fact_id), &voter, fact.voting_ends_at);
+        charge_vote_fee(&env, &voter);
+
+        // Abstainers join the voter list but not the direction map
+        fact.voters.push_back(voter.clone());
+        fact.abstain_votes = add_votes(fact.abstain_votes, 1);
+        note_voter(&env, &voter);
+        let abstained_key = (ABSTAINED_PREFIX, fact_id, voter.clone());
+        env.storage().persistent().set(&abstained_key, &true);
+        extend_persistent_ttl(&env, &abstained_key);
+
+        // Save the updated fact
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+
+        // Add the fact to the voter's history
+        let history_key = (HISTORY_PREFIX, voter);
+        let mut history: Vec<u32> = env.storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or(Vec::new(&env));
+        history.push_back(fact_id);
+        env.storage().persistent().set(&history_key, &history);
+        extend_persistent_ttl(&env, &history_key);
+    }
+
+    /// Delegate voting to another address
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * delegator - The address handing over its vote
+    /// * delegate_to - The address allowed to vote on the delegator's behalf
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    /// * If the delegator tries to delegate to itself
+    pub fn delegate(env: Env, delegator: Address, delegate_to: Address) {
+        require_not_closed(&env);
+
+        // Require authorization from the delegator
+        delegator.require_auth();
+
+        if delegator == delegate_to {
+            panic!("Cannot delegate to self");
+        }
+
+        let delegate_key = (DELEGATE_PREFIX, delegator);
+        env.storage().persistent().set(&delegate_key, &delegate_to);
+        extend_persistent_ttl(&env, &delegate_key);
+    }
+
+    /// Vote on a fact on behalf of a delegator
+    /// 
+    /// The vote is recorded under the delegator's address, so it counts once
+    /// per delegator and conflicts with any direct vote the delegator casts.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * delegate - The address casting the vote
+    /// * delegator - The address the vote is cast for
+    /// * fact_id - The ID of the fact to vote on
+    /// * is_true - True for "true" vote, false for "false" vote
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If `delegate` is not the delegator's current delegate
+    /// * If the vote itself is rejected (see `vote`)
+    pub fn vote_for(env: Env, delegate: Address, delegator: Address, fact_id: u32, is_true: bool) {
+        // Require authorization from the delegate
+        delegate.require_auth();
+        require_not_paused(&env);
+
+        let current: Option<Address> = env.storage()
+            .persistent()
+            .get(&(DELEGATE_PREFIX, delegator.clone()));
+        if current != Some(delegate.clone()) {
+            panic!("Not a delegate for this address");
+        }
+
+        let weight = vote_weight(&env, &delegator, fact_id);
+        record_vote(&env, &delegator, &delegate, fact_id, is_true, weight, false);
+    }
+
+    /// Register the ed25519 public key that signs a voter's off-chain votes
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address the signed votes are cast for
+    /// * public_key - The key that signs them, replacing any earlier one
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    pub fn register_vote_key(env: Env, voter: Address, public_key: BytesN<32>) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        let key_key = (VOTE_KEY_PREFIX, voter);
+        env.storage().persistent().set(&key_key, &public_key);
+        extend_persistent_ttl(&env, &key_key);
+    }
+
+    /// Get the nonce a voter's next signed vote must be made over
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address the signed votes are cast for
+    /// 
+    /// # Returns
+    /// * u64 - The number of signed votes applied for the voter so far
+    pub fn get_vote_nonce(env: Env, voter: Address) -> u64 {
+        vote_nonce(&env, &voter)
+    }
+
+    /// Apply a batch of votes collected and signed off-chain
+    /// 
+    /// Each entry is a voter, their choice, and their signature over the
+    /// XDR encoding of `(contract, fact_id, is_true, nonce)` made with the
+    /// key they registered through `register_vote_key`, where `contract` is
+    /// this contract's address and `nonce` is the voter's current
+    /// `get_vote_nonce`. Applying a vote uses up the nonce, so a signature
+    /// can't be replayed after a retraction or reset, or on another
+    /// deployment. Entries from voters without a registered key, voters who
+    /// have already voted (including earlier in the same batch) or are within
+    /// the vote cooldown, the creator, and entries past the fact's vote cap
+    /// are skipped, as is the rest of the batch once the fact settles or its
+    /// voting window closes. Signatures are checked by the host, so one that
+    /// doesn't verify fails the whole batch; relayers should check them
+    /// before submitting. Votes are weighted like `vote`; the relayer pays
+    /// each applied vote's fee and deposit, and gets the deposit back when
+    /// the fact settles.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * relayer - The address submitting the batch and paying its costs
+    /// * fact_id - The ID of the fact to vote on
+    /// * votes - (voter, is_true, signature) entries
+    /// 
+    /// # Returns
+    /// * u32 - The number of votes applied
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If an applied vote's signature doesn't verify
+    /// * If a fee or deposit is set but no token has been configured
+    /// * If an applied vote is rejected (see `vote`)
+    pub fn submit_votes_signed(env: Env, relayer: Address, fact_id: u32, votes: Vec<(Address, bool, BytesN<64>)>) -> u32 {
+        // Require authorization from the relayer
+        relayer.require_auth();
+        require_not_paused(&env);
+
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut voted = load_votes(&env, fact_id);
+        let mut applied = 0;
+
+        for (voter, is_true, signature) in votes.iter() {
+            // Nothing more can be applied once the fact settles or voting closes,
+            // which an earlier entry may have caused by reaching auto-verify
+            let fact: Fact = env.storage()
+                .persistent()
+                .get(&fact_key)
+                .expect("Fact not found");
+            if !fact.is_open() || env.ledger().timestamp() > fact.voting_ends_at {
+                break;
+            }
+
+            let public_key: BytesN<32> = match env.storage().persistent().get(&(VOTE_KEY_PREFIX, voter.clone())) {
+                Some(public_key) => public_key,
+                None => continue,
+            };
+
+            // Skip entries the fact would refuse rather than failing the batch
+            let capped = fact.max_votes > 0 && fact.total_votes() >= fact.max_votes as u64;
+            let already_voted = voted.contains_key(voter.clone()) || has_abstained(&env, fact_id, &voter);
+            if capped || voter == fact.creator || already_voted || in_vote_cooldown(&env, &voter) {
+                continue;
+            }
+
+            let nonce = vote_nonce(&env, &voter);
+            let message = (env.current_contract_address(), fact_id, is_true, nonce).to_xdr(&env);
+            env.crypto().ed25519_verify(&public_key, &message, &signature);
+            let nonce_key = (VOTE_NONCE_PREFIX, voter.clone());
+            env.storage().persistent().set(&nonce_key, &(nonce + 1));
+            extend_persistent_ttl(&env, &nonce_key);
+
+            let weight = vote_weight(&env, &voter, fact_id);
+            take_vote_deposit(&env, &relayer, &voter, fact_id);
+            record_vote(&env, &voter, &relayer, fact_id, is_true, weight, false);
+            voted.set(voter, is_true);
+            applied += 1;
+        }
+
+        applied
+    }
+
+    /// Vote on a fact with a token stake that weights the vote
+    /// 
+    /// The stake is transferred from the voter to the contract and adds to
+    /// the chosen counter by its amount instead of by 1. It comes back when
+    /// the vote is retracted or, once the fact is settled, via `claim_stake`.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the fact to vote on
+    /// * is_true - True for "true" vote, false for "false" vote
+    /// * stake - The amount of tokens to stake on the vote
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the voter voted within the vote cooldown
+    /// * If the stake is not positive or does not fit a vote counter
+    /// * If no stake token has been configured
+    /// * If the vote itself is rejected (see `vote`)
+    pub fn vote_with_stake(env: Env, voter: Address, fact_id: u32, is_true: bool, stake: i128) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        if stake <= 0 || stake > u32::MAX as i128 {
+            panic!("Invalid stake");
+        }
+        let token_address: Address = env.storage()
+            .instance()
+            .get(&TOKEN)
+            .expect("Token not configured");
+
+        record_vote(&env, &voter, &voter, fact_id, is_true, stake as u32, false);
+
+        // Move the stake into the contract
+        token::Client::new(&env, &token_address)
+            .transfer(&voter, env.current_contract_address(), &stake);
+        adjust_held(&env, stake);
+
+        // Remember the stake so it can be refunded later
+        let stakes_key = (STAKES_PREFIX, fact_id);
+        let mut stakes = load_stakes(&env, fact_id);
+        stakes.set(voter, stake);
+        env.storage().persistent().set(&stakes_key, &stakes);
+        extend_persistent_ttl(&env, &stakes_key);
+    }
+
+    /// Withdraw the stake from a vote once the fact is no longer open
+    /// 
+    /// The vote keeps counting in the settled tally; only the tokens return.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address that staked
+    /// * fact_id - The ID of the fact the stake was placed on
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    /// * If the fact is still accepting votes
+    /// * If the voter has no unclaimed stake on the fact
+    pub fn claim_stake(env: Env, voter: Address, fact_id: u32) {
+        // Require authorization from the voter
+        voter.require_auth();
+
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&(FACT_PREFIX, fact_id))
+            .expect("Fact not found");
+        if fact.is_open() {
+            panic!("Fact not finalized");
+        }
+
+        // Removing the stake marks it claimed
+        let stakes_key = (STAKES_PREFIX, fact_id);
+        let mut stakes = load_stakes(&env, fact_id);
+        let stake = stakes.get(voter.clone()).expect("No stake to claim");
+        stakes.remove(voter.clone());
+        env.storage().persistent().set(&stakes_key, &stakes);
+
+        let token_address: Address = env.storage()
+            .instance()
+            .get(&TOKEN)
+            .expect("Token not configured");
+        token::Client::new(&env, &token_address)
+            .transfer(&env.current_contract_address(), &voter, &stake);
+        adjust_held(&env, -stake);
+    }
+
+    /// Commit to a hidden vote on a sealed fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the sealed fact
+    /// * hash - `sha256(is_true || salt)`, where `is_true` is a single byte (1 or 0)
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist or doesn't use commit-reveal voting
+    /// * If the fact is no longer accepting votes
+    /// * If the voter has already committed to this fact
+    pub fn commit_vote(env: Env, voter: Address, fact_id: u32, hash: BytesN<32>) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        if !fact.sealed {
+            panic!("Fact does not use commit-reveal voting");
+        }
+        if !fact.is_open() {
+            panic!("Fact already finalized");
+        }
+        if env.ledger().timestamp() > fact.voting_ends_at {
+            panic!("Voting period has ended");
+        }
+
+        // Only one commitment per voter
+        let commits_key = (COMMITS_PREFIX, fact_id);
+        let mut commits: Map<Address, BytesN<32>> = env.storage()
+            .persistent()
+            .get(&commits_key)
+            .unwrap_or(Map::new(&env));
+        if commits.contains_key(voter.clone()) {
+            panic!("Already committed to this fact");
+        }
+
+        commits.set(voter, hash);
+        env.storage().persistent().set(&commits_key, &commits);
+        extend_persistent_ttl(&env, &commits_key);
+    }
+
+    /// Reveal a previously committed vote, applying it to the tally
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the sealed fact
+    /// * is_true - The committed vote direction
+    /// * salt - The salt used when computing the commitment
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the voter has no commitment for this fact
+    /// * If the revealed vote doesn't match the commitment
+    /// * If the reveal window hasn't opened yet or has closed
+    /// * If the vote itself is rejected (see `vote`)
+    pub fn reveal_vote(env: Env, voter: Address, fact_id: u32, is_true: bool, salt: BytesN<32>) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        let commits_key = (COMMITS_PREFIX, fact_id);
+        let mut commits: Map<Address, BytesN<32>> = env.storage()
+            .persistent()
+            .get(&commits_key)
+            .unwrap_or(Map::new(&env));
+        let commitment = commits.get(voter.clone()).expect("No commitment for this fact");
+
+        // Recompute the commitment from the revealed vote
+        let mut preimage = Bytes::new(&env);
+        preimage.push_back(is_true as u8);
+        preimage.append(&salt.into());
+        if env.crypto().sha256(&preimage).to_bytes() != commitment {
+            panic!("Reveal does not match commitment");
+        }
+
+        let weight = vote_weight(&env, &voter, fact_id);
+        take_vote_deposit(&env, &voter, &voter, fact_id);
+        record_vote(&env, &voter, &voter, fact_id, is_true, weight, true);
+
+        // The commitment has been used up
+        commits.remove(voter);
+        env.storage().persistent().set(&commits_key, &commits);
+    }
+
+    /// Retract a previously cast vote on a fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the fact to retract the vote from
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the fact has already been finalized
+    /// * If the voting period has ended
+    /// * If the voter has not voted on this fact
+    /// 
+    /// A staked vote has its stake refunded on retraction.
+    pub fn retract_vote(env: Env, voter: Address, fact_id: u32) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Settled facts no longer accept vote changes
+        if !fact.is_open() {
+            panic!("Fact already finalized");
+        }
+
+        // Votes can only be changed within the voting window
+        if env.ledger().timestamp() > fact.voting_ends_at {
+            panic!("Voting period has ended");
+        }
+
+        // Look up the direction the voter chose
+        let votes_key = (VOTES_PREFIX, fact_id);
+        let mut votes = load_votes(&env, fact_id);
+        let was_true = votes.get(voter.clone()).expect("Not voted on this fact");
+
+        // Remove the voter from the map and the list
+        votes.remove(voter.clone());
+        if let Some(index) = fact.voters.first_index_of(&voter) {
+            fact.voters.remove(index);
+        }
+
+        // Drop the fact from the voter's history
+        let history_key = (HISTORY_PREFIX, voter.clone());
+        let mut history: Vec<u32> = env.storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or(Vec::new(&env));
+        if let Some(index) = history.first_index_of(fact_id) {
+            history.remove(index);
+            env.storage().persistent().set(&history_key, &history);
+        }
+
+        // Staked votes get their stake refunded
+        let stakes_key = (STAKES_PREFIX, fact_id);
+        let mut stakes = load_stakes(&env, fact_id);
+        if let Some(stake) = stakes.get(voter.clone()) {
+            let token_address: Address = env.storage()
+                .instance()
+                .get(&TOKEN)
+                .expect("Token not configured");
+            token::Client::new(&env, &token_address)
+                .transfer(&env.current_contract_address(), &voter, &stake);
+            adjust_held(&env, -stake);
+            stakes.remove(voter.clone());
+            env.storage().persistent().set(&stakes_key, &stakes);
+        }
+
+        // A vote deposit is forfeited to the contract treasury
+        let deposits_key = (DEPOSITS_PREFIX, fact_id);
+        let mut deposits: Map<Address, i128> = env.storage()
+            .persistent()
+            .get(&deposits_key)
+            .unwrap_or(Map::new(&env));
+        if let Some(deposit) = deposits.get(voter.clone()) {
+            deposits.remove(voter.clone());
+            env.storage().persistent().set(&deposits_key, &deposits);
+            let mut payers = load_deposit_payers(&env, fact_id);
+            if payers.contains_key(voter.clone()) {
+                payers.remove(voter.clone());
+                env.storage().persistent().set(&(DEPOSIT_PAYERS_PREFIX, fact_id), &payers);
+            }
+            let treasury: i128 = env.storage().instance().get(&TREASURY).unwrap_or(0);
+            env.storage().instance().set(&TREASURY, &(treasury + deposit));
+            adjust_held(&env, -deposit);
+        }
+
+        // Forget any reason given for the vote
+        let reasons_key = (REASONS_PREFIX, fact_id);
+        let mut reasons = load_reasons(&env, fact_id);
+        if reasons.contains_key(voter.clone()) {
+            reasons.remove(voter.clone());
+            env.storage().persistent().set(&reasons_key, &reasons);
+        }
+
+        // Forget the weight the vote was counted with
+        let weights_key = (WEIGHTS_PREFIX, fact_id);
+        let mut weights = load_weights(&env, fact_id);
+        let weight = weights.get(voter.clone()).unwrap_or(1);
+        weights.remove(voter.clone());
+        env.storage().persistent().set(&weights_key, &weights);
+
+        // Decrement the counter the voter contributed to
+        if was_true {
+            fact.true_votes -= weight;
+        } else {
+            fact.false_votes -= weight;
+        }
+        let total_votes: u64 = env.storage().instance().get(&TOTAL_VOTES).unwrap_or(0);
+        env.storage().instance().set(&TOTAL_VOTES, &(total_votes - weight as u64));
+        unlog_vote_time(&env, fact_id, &voter);
+
+        // Save the updated fact and votes
+        env.storage().persistent().set(&fact_key, &fact);
+        env.storage().persistent().set(&votes_key, &votes);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+        extend_persistent_ttl(&env, &votes_key);
+    }
+
+    /// Change the direction of an existing vote on a fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * fact_id - The ID of the fact the vote was cast on
+    /// * new_is_true - The new vote direction
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the fact has already been finalized
+    /// * If the voting period has ended
+    /// * If the voter has not voted on this fact
+    pub fn change_vote(env: Env, voter: Address, fact_id: u32, new_is_true: bool) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Settled facts no longer accept vote changes
+        if !fact.is_open() {
+            panic!("Fact already finalized");
+        }
+
+        // Votes can only be changed within the voting window
+        if env.ledger().timestamp() > fact.voting_ends_at {
+            panic!("Voting period has ended");
+        }
+
+        // Look up the direction the voter chose
+        let votes_key = (VOTES_PREFIX, fact_id);
+        let mut votes = load_votes(&env, fact_id);
+        let was_true = votes.get(voter.clone()).expect("Not voted on this fact");
+
+        // Nothing to do if the direction is unchanged
+        if was_true == new_is_true {
+            return;
+        }
+
+        // Move the vote's weight from the old counter to the new one
+        let weight = load_weights(&env, fact_id).get(voter.clone()).unwrap_or(1);
+        votes.set(voter.clone(), new_is_true);
+        if new_is_true {
+            fact.false_votes -= weight;
+            fact.true_votes = add_votes(fact.true_votes, weight);
+        } else {
+            fact.true_votes -= weight;
+            fact.false_votes = add_votes(fact.false_votes, weight);
+        }
+
+        // The timeline dates the vote from when it changed direction
+        unlog_vote_time(&env, fact_id, &voter);
+        log_vote_time(&env, fact_id, Some(voter.clone()), new_is_true, weight);
+
+        // Save the updated fact and votes
+        env.storage().persistent().set(&fact_key, &fact);
+        env.storage().persistent().set(&votes_key, &votes);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+        extend_persistent_ttl(&env, &votes_key);
+    }
+
+    /// Finalize a fact, settling its status from the vote tally
+    /// 
+    /// A fact is Verified when true votes lead by at least the verdict
+    /// margin, Debunked when false votes do, and Disputed otherwise. When the
+    /// admin has set a supermajority, a side instead needs at least that
+    /// percentage of the votes to win. Votes from blacklisted addresses, and
+    /// from addresses below the reputation floor when one is set, are left
+    /// out of the verdict, though the stored counts keep them. An exact tie
+    /// is settled by the tie policy (see `set_tie_policy`), which may extend
+    /// voting instead of settling the fact.
+    /// Voters on the winning side of a decisive verdict gain reputation
+    /// the first time the fact is finalized.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to finalize
+    /// 
+    /// # Returns
+    /// * FactStatus - The status the fact was settled with, or its unchanged
+    ///   status when voting was extended
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    /// * If the fact doesn't exist
+    /// * If the finalize mode requires an authorization that is missing
+    /// * If the fact has already been finalized
+    /// * If the voting period hasn't ended
+    /// * If the fact is sealed and its reveal window hasn't closed
+    /// * If fewer votes than the minimum quorum have been cast
+    pub fn finalize_fact(env: Env, fact_id: u32) -> FactStatus {
+        require_not_closed(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        require_finalize_auth(&env, &fact);
+
+        if !fact.is_open() {
+            panic!("Fact already finalized");
+        }
+
+        // Verdicts wait until everyone has had their chance to vote
+        if env.ledger().timestamp() <= fact.voting_ends_at {
+            panic!("Voting period still open");
+        }
+
+        // Sealed tallies are only stable once nobody can reveal any more
+        if fact.sealed && env.ledger().timestamp() <= fact.reveal_ends_at {
+            panic!("Reveal window still open");
+        }
+
+        let (status, votes) = tally_verdict(&env, &fact).expect("Quorum not reached");
+        if status == FactStatus::Open {
+            extend_tied_vote(&env, &mut fact);
+        } else {
+            settle_fact(&env, &mut fact, status, &votes);
+        }
+
+        // Save the updated fact
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+
+        fact.status
+    }
+
+    /// Finalize every open fact whose voting window has closed
+    /// 
+    /// Meant to be called periodically by an operator. Facts still open for
+    /// voting at `now`, already finalized or deleted facts, sealed facts
+    /// whose reveal window hasn't closed, and facts short of the quorum are
+    /// skipped rather than failing the whole call. Ties the tie policy extends
+    /// are saved with their new deadline but not returned. In `AdminOnly`
+    /// mode the admin authorizes the whole sweep once; in `CreatorOnly` mode
+    /// each creator has to finalize their own facts, so nothing is finalized.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * now - The timestamp a fact's voting must have ended by, no later
+    ///   than the current ledger time
+    /// 
+    /// # Returns
+    /// * Vec<u32> - The IDs of the facts that were finalized, in ascending order
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    /// * If `now` is later than the current ledger time
+    /// * If the finalize mode requires an authorization that is missing
+    pub fn finalize_due(env: Env, now: u64) -> Vec<u32> {
+        require_not_closed(&env);
+
+        // A cutoff in the future would settle facts still being voted on
+        if now > env.ledger().timestamp() {
+            panic!("Cutoff is in the future");
+        }
+
+        // Authorize the sweep once, since a single invocation can't satisfy
+        // the same address's authorization for every fact
+        let mut finalized = Vec::new(&env);
+        match env.storage().instance().get(&FINALIZE_MODE).unwrap_or(FinalizeMode::Anyone) {
+            FinalizeMode::Anyone => {}
+            FinalizeMode::AdminOnly => {
+                require_admin(&env);
+            }
+            FinalizeMode::CreatorOnly => return finalized,
+        }
+
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            let mut fact: Fact = match env.storage().persistent().get(&fact_key) {
+                Some(fact) => fact,
+                None => continue,
+            };
+            if !fact.is_open() || fact.voting_ends_at >= now {
+                continue;
+            }
+            if fact.sealed && env.ledger().timestamp() <= fact.reveal_ends_at {
+                continue;
+            }
+
+            let Some((status, votes)) = tally_verdict(&env, &fact) else {
+                continue;
+            };
+            if status == FactStatus::Open {
+                extend_tied_vote(&env, &mut fact);
+            } else {
+                settle_fact(&env, &mut fact, status, &votes);
+                finalized.push_back(id);
+            }
+            env.storage().persistent().set(&fact_key, &fact);
+            extend_persistent_ttl(&env, &fact_key);
+        }
+
+        finalized
+    }
+
+    /// Challenge a finalized fact, reopening it for voting as Disputed
+    /// 
+    /// If the voting window has already closed it is extended by the
+    /// fact's original voting duration.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * challenger - The address raising the challenge
+    /// * fact_id - The ID of the fact to challenge
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the fact has been deleted
+    /// * If the fact is not Verified or Debunked
+    pub fn challenge_fact(env: Env, challenger: Address, fact_id: u32) {
+        // Require authorization from the challenger
+        challenger.require_auth();
+        require_not_paused(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Deleted facts are out of the status counts and stay closed
+        if fact.deleted {
+            panic!("Fact has been deleted");
+        }
+
+        // Only settled verdicts can be challenged
+        if fact.status != FactStatus::Verified && fact.status != FactStatus::Debunked {
+            panic!("Only finalized facts can be challenged");
+        }
+
+        // Reopen voting under a Disputed status
+        move_status_count(&env, &fact, FactStatus::Disputed);
+        fact.status = FactStatus::Disputed;
+        fact.reopened = true;
+        fact.challengers.push_back(challenger);
+
+        let now = env.ledger().timestamp();
+        if now > fact.voting_ends_at {
+            let reveal_period = fact.reveal_ends_at - fact.voting_ends_at;
+            fact.voting_ends_at = now.saturating_add(fact.voting_ends_at - fact.created_at);
+            fact.reveal_ends_at = fact.voting_ends_at.saturating_add(reveal_period);
+        }
+
+        // Save the updated fact
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+    }
+
+    /// Remove any fact from storage (admin only)
+    /// 
+    /// Its votes are cleared as in `admin_reset_votes`, refunding stakes and
+    /// deposits, and every record kept for the fact is removed with it.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to remove
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn admin_remove_fact(env: Env, fact_id: u32) {
+        let admin = require_admin(&env);
+
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Deleted facts were already taken out of their category and
+        // status counts and released their text
+        if !fact.deleted {
+            adjust_category_count(&env, &fact.category, false);
+            adjust_status_count(&env, fact.status, false);
+            release_content(&env, &fact.text);
+        }
+
+        // Remove the fact, its votes and everything else recorded for it
+        clear_votes(&env, &mut fact);
+        env.storage().persistent().remove(&fact_key);
+        env.storage().persistent().remove(&(BALANCES_PREFIX, fact_id));
+        env.storage().persistent().remove(&(FLAGGERS_PREFIX, fact_id));
+        env.storage().persistent().remove(&(COMMENTS_PREFIX, fact_id));
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("remove"), admin, fact_id }.publish(&env);
+    }
+
+    /// Clear all votes on a fact so it can be voted on afresh (admin only)
+    /// 
+    /// Stakes are refunded and the fact is dropped from each voter's history.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to reset
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn admin_reset_votes(env: Env, fact_id: u32) {
+        let admin = require_admin(&env);
+
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Zero the tally and forget who voted
+        clear_votes(&env, &mut fact);
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("reset"), admin, fact_id }.publish(&env);
+    }
+
+    /// Fold the votes of a duplicate fact into the fact being kept (admin only)
+    /// 
+    /// Votes and abstentions on the merged fact move to the kept fact with
+    /// their recorded weights, except from voters who already voted or
+    /// abstained on the kept fact, whose original vote stands. Stakes move
+    /// with their votes and are claimed from the kept fact; stakes on votes
+    /// that don't move are refunded. The kept fact is verified on the spot if
+    /// the merged votes reach the auto-verify threshold. Moved votes bring
+    /// their reasons, balance snapshots and timeline entries along. The
+    /// merged fact is then deleted with its votes cleared, and its vote
+    /// deposits are refunded.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * keep_id - The ID of the fact that absorbs the votes
+    /// * merge_id - The ID of the duplicate fact to fold in
+    /// 
+    /// # Panics
+    /// * If both IDs are the same
+    /// * If either fact doesn't exist
+    /// * If either fact is no longer open
+    /// * If either fact uses anonymous or commit-reveal voting
+    pub fn merge_facts(env: Env, keep_id: u32, merge_id: u32) {
+        let admin = require_admin(&env);
+
+        if keep_id == merge_id {
+            panic!("Cannot merge a fact into itself");
+        }
+
+        let keep_key = (FACT_PREFIX, keep_id);
+        let merge_key = (FACT_PREFIX, merge_id);
+        let mut keep: Fact = env.storage()
+            .persistent()
+            .get(&keep_key)
+            .expect("Fact not found");
+        let mut merged: Fact = env.storage()
+            .persistent()
+            .get(&merge_key)
+            .expect("Fact not found");
+        if !keep.is_open() || !merged.is_open() {
+            panic!("Fact already finalized");
+        }
+        if keep.anonymous || keep.sealed || merged.anonymous || merged.sealed {
+            panic!("Cannot merge facts with hidden votes");
+        }
+
+        let keep_votes_key = (VOTES_PREFIX, keep_id);
+        let keep_weights_key = (WEIGHTS_PREFIX, keep_id);
+        let mut keep_votes = load_votes(&env, keep_id);
+        let mut keep_weights = load_weights(&env, keep_id);
+        let merged_votes = load_votes(&env, merge_id);
+        let merged_weights = load_weights(&env, merge_id);
+        let keep_stakes_key = (STAKES_PREFIX, keep_id);
+        let mut keep_stakes = load_stakes(&env, keep_id);
+        let mut merged_stakes = load_stakes(&env, merge_id);
+        let keep_reasons_key = (REASONS_PREFIX, keep_id);
+        let mut keep_reasons = load_reasons(&env, keep_id);
+        let merged_reasons = load_reasons(&env, merge_id);
+        let keep_snapshots_key = (BALANCES_PREFIX, keep_id);
+        let mut keep_snapshots: Map<Address, i128> = env.storage()
+            .persistent()
+            .get(&keep_snapshots_key)
+            .unwrap_or(Map::new(&env));
+        let merged_snapshots: Map<Address, i128> = env.storage()
+            .persistent()
+            .get(&(BALANCES_PREFIX, merge_id))
+            .unwrap_or(Map::new(&env));
+        let mut moved: Map<Address, bool> = Map::new(&env);
+        let mut dropped_votes: u64 = 0;
+
+        for voter in merged.voters.iter() {
+            // The duplicate leaves the voter's history either way
+            let history_key = (HISTORY_PREFIX, voter.clone());
+            let mut history: Vec<u32> = env.storage()
+                .persistent()
+                .get(&history_key)
+                .unwrap_or(Vec::new(&env));
+            if let Some(index) = history.first_index_of(merge_id) {
+                history.remove(index);
+            }
+            if merged.abstain_votes > 0 {
+                env.storage().persistent().remove(&(ABSTAINED_PREFIX, merge_id, voter.clone()));
+            }
+
+            // Nobody is counted twice, and the creator can't vote on their own fact
+            if voter == keep.creator || keep_votes.contains_key(voter.clone()) || has_abstained(&env, keep_id, &voter) {
+                if merged_votes.contains_key(voter.clone()) {
+                    dropped_votes += merged_weights.get(voter.clone()).unwrap_or(1) as u64;
+                }
+                env.storage().persistent().set(&history_key, &history);
+                continue;
+            }
+            match merged_votes.get(voter.clone()) {
+                Some(is_true) => {
+                    let weight = merged_weights.get(voter.clone()).unwrap_or(1);
+                    if is_true {
+                        keep.true_votes = add_votes(keep.true_votes, weight);
+                    } else {
+                        keep.false_votes = add_votes(keep.false_votes, weight);
+                    }
+                    keep_votes.set(voter.clone(), is_true);
+                    keep_weights.set(voter.clone(), weight);
+
+                    // A staked vote's stake follows it, so it's claimed from the kept fact
+                    if let Some(stake) = merged_stakes.get(voter.clone()) {
+                        keep_stakes.set(voter.clone(), stake);
+                        merged_stakes.remove(voter.clone());
+                    }
+
+                    // So do its reason and balance snapshot
+                    if let Some(reason) = merged_reasons.get(voter.clone()) {
+                        keep_reasons.set(voter.clone(), reason);
+                    }
+                    if let Some(balance) = merged_snapshots.get(voter.clone()) {
+                        if !keep_snapshots.contains_key(voter.clone()) {
+                            keep_snapshots.set(voter.clone(), balance);
+                        }
+                    }
+                    moved.set(voter.clone(), true);
+                }
+                None => {
+                    keep.abstain_votes += 1;
+                    let abstained_key = (ABSTAINED_PREFIX, keep_id, voter.clone());
+                    env.storage().persistent().set(&abstained_key, &true);
+                    extend_persistent_ttl(&env, &abstained_key);
+                }
+            }
+            keep.voters.push_back(voter.clone());
+
+            // The kept fact takes the duplicate's place in the voter's history
+            history.push_back(keep_id);
+            env.storage().persistent().set(&history_key, &history);
+            extend_persistent_ttl(&env, &history_key);
+        }
+
+        // Moved votes keep their place in the timeline
+        let mut keep_timeline = load_timeline(&env, keep_id);
+        for entry in load_timeline(&env, merge_id).iter() {
+            if entry.1.clone().is_some_and(|voter| moved.contains_key(voter)) {
+                keep_timeline.push_back(entry);
+            }
+        }
+
+        // The merged votes may carry the kept fact past the auto-verify threshold
+        let auto_verify: u32 = env.storage().instance().get(&AUTO_VERIFY).unwrap_or(0);
+        if auto_verify > 0 && keep.true_votes >= auto_verify {
+            settle_fact(&env, &mut keep, FactStatus::Verified, &keep_votes);
+        }
+
+        env.storage().persistent().set(&keep_key, &keep);
+        env.storage().persistent().set(&keep_votes_key, &keep_votes);
+        env.storage().persistent().set(&keep_weights_key, &keep_weights);
+        env.storage().persistent().set(&keep_stakes_key, &keep_stakes);
+        env.storage().persistent().set(&keep_reasons_key, &keep_reasons);
+        env.storage().persistent().set(&keep_snapshots_key, &keep_snapshots);
+        env.storage().persistent().set(&(TIMELINE_PREFIX, keep_id), &keep_timeline);
+        extend_persistent_ttl(&env, &keep_key);
+        extend_persistent_ttl(&env, &keep_votes_key);
+        extend_persistent_ttl(&env, &keep_weights_key);
+        extend_persistent_ttl(&env, &keep_stakes_key);
+        extend_persistent_ttl(&env, &keep_reasons_key);
+        extend_persistent_ttl(&env, &keep_snapshots_key);
+        extend_persistent_ttl(&env, &(TIMELINE_PREFIX, keep_id));
+
+        // Stakes on votes that weren't carried over go back to their voters
+        if !merged_stakes.is_empty() {
+            let token_address: Address = env.storage()
+                .instance()
+                .get(&TOKEN)
+                .expect("Token not configured");
+            let token = token::Client::new(&env, &token_address);
+            for (voter, stake) in merged_stakes.iter() {
+                token.transfer(&env.current_contract_address(), &voter, &stake);
+                adjust_held(&env, -stake);
+            }
+        }
+        env.storage().persistent().remove(&(STAKES_PREFIX, merge_id));
+
+        // Votes that were already on the kept fact are only counted there now
+        let total_votes: u64 = env.storage().instance().get(&TOTAL_VOTES).unwrap_or(0);
+        env.storage().instance().set(&TOTAL_VOTES, &total_votes.saturating_sub(dropped_votes));
+
+        // Retire the duplicate the way a deletion would, leaving it no votes
+        // for a later removal to take out of the totals again
+        refund_deposits(&env, merge_id);
+        adjust_category_count(&env, &merged.category, false);
+        adjust_status_count(&env, merged.status, false);
+        release_content(&env, &merged.text);
+        merged.true_votes = 0;
+        merged.false_votes = 0;
+        merged.abstain_votes = 0;
+        merged.voters = Vec::new(&env);
+        env.storage().persistent().remove(&(VOTES_PREFIX, merge_id));
+        env.storage().persistent().remove(&(WEIGHTS_PREFIX, merge_id));
+        env.storage().persistent().remove(&(REASONS_PREFIX, merge_id));
+        env.storage().persistent().remove(&(TIMELINE_PREFIX, merge_id));
+        env.storage().persistent().remove(&(BALANCES_PREFIX, merge_id));
+        merged.deleted = true;
+        env.storage().persistent().set(&merge_key, &merged);
+        extend_persistent_ttl(&env, &merge_key);
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("merge"), admin, fact_id: keep_id }.publish(&env);
+    }
+
+    /// Extend the storage TTL of several facts without reading them
+    /// 
+    /// The contract instance is bumped too. IDs that don't exist are skipped.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * ids - The IDs of the facts to keep alive
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    pub fn bump_ttl(env: Env, ids: Vec<u32>) {
+        require_not_closed(&env);
+
+        for id in ids.iter() {
+            let fact_key = (FACT_PREFIX, id);
+            if env.storage().persistent().has(&fact_key) {
+                extend_persistent_ttl(&env, &fact_key);
+            }
+        }
+
+        extend_instance_ttl(&env);
+    }
+
+    /// Exclude an address's votes from finalization (admin only)
+    /// 
+    /// Votes on anonymous facts can't be traced to an address and are
+    /// unaffected.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * addr - The address to blacklist
+    pub fn blacklist(env: Env, addr: Address) {
+        let admin = require_admin(&env);
+
+        let blacklist_key = (BLACKLIST_PREFIX, addr);
+        if !env.storage().persistent().has(&blacklist_key) {
+            env.storage().persistent().set(&blacklist_key, &true);
+            let count: u32 = env.storage().instance().get(&BLACKLIST_COUNT).unwrap_or(0);
+            env.storage().instance().set(&BLACKLIST_COUNT, &(count + 1));
+        }
+        extend_persistent_ttl(&env, &blacklist_key);
+
+        // Record the moderation action
+        Moderation { action: symbol_short!("blacklist"), admin, fact_id: 0 }.publish(&env);
+    }
+
+    /// Count an address's votes at finalization again (admin only)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * addr - The address to remove from the blacklist
+    pub fn unblacklist(env: Env, addr: Address) {
+        let admin = require_admin(&env);
+
+        let blacklist_key = (BLACKLIST_PREFIX, addr);
+        if env.storage().persistent().has(&blacklist_key) {
+            env.storage().persistent().remove(&blacklist_key);
+            let count: u32 = env.storage().instance().get(&BLACKLIST_COUNT).unwrap_or(0);
+            env.storage().instance().set(&BLACKLIST_COUNT, &count.saturating_sub(1));
+        }
+
+        // Record the moderation action
+        Moderation { action: Symbol::new(&env, "unblacklist"), admin, fact_id: 0 }.publish(&env);
+    }
+
+    /// Get the reputation of an address
+    /// 
+    /// Reputation decays by a point for every `REPUTATION_DECAY_PERIOD` that
+    /// passes without the address earning more, down to zero.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * addr - The address to look up
+    /// 
+    /// # Returns
+    /// * i32 - The number of finalized facts the address voted with the
+    ///   majority on, less any decay
+    pub fn get_reputation(env: Env, addr: Address) -> i32 {
+        decayed_reputation(&env, &addr)
+    }
+
+    /// Edit the text of a fact before any votes have been cast
+    /// 
+    /// The replaced text is appended to the fact's history, which keeps the
+    /// most recent `MAX_HISTORY` versions.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to edit
+    /// * new_text - The corrected fact text
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the creator of the fact has not authorized the call
+    /// * If any vote or vote commitment has been made on the fact
+    pub fn edit_fact(env: Env, fact_id: u32, new_text: String) {
+        require_not_paused(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Only the creator may edit the fact
+        fact.creator.require_auth();
+
+        // Anonymous votes only show in the tally and sealed votes only in
+        // the commitments, so check all three
+        let has_commits = env.storage()
+            .persistent()
+            .get::<_, Map<Address, BytesN<32>>>(&(COMMITS_PREFIX, fact_id))
+            .is_some_and(|commits| !commits.is_empty());
+        if !fact.voters.is_empty() || fact.total_votes() > 0 || has_commits {
+            panic!("Cannot edit after voting started");
+        }
+
+        validate_text(&new_text);
+        if new_text != fact.text {
+            release_content(&env, &fact.text);
+            register_content(&env, &new_text, fact_id);
+            if fact.history.len() >= MAX_HISTORY {
+                fact.history.pop_front();
+            }
+            fact.history.push_back(fact.text.clone());
+            fact.text = new_text;
+        }
+
+        // Save the updated fact
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+    }
+
+    /// Set when a time-sensitive fact goes stale
+    /// 
+    /// Once the expiry has passed, `sweep_expired` moves the fact to the
+    /// Expired status if it hasn't been settled by then. Only open facts take
+    /// an expiry, so creators can't use it to hide a verdict.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// * expires_at - The timestamp after which the fact expires, or 0 for never
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the creator has not authorized the call
+    /// * If the fact is no longer open
+    /// * If the expiry isn't in the future
+    pub fn set_fact_expiry(env: Env, fact_id: u32, expires_at: u64) {
+        require_not_paused(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Only the creator may set the expiry
+        fact.creator.require_auth();
+
+        if !fact.is_open() {
+            panic!("Fact already finalized");
+        }
+        if expires_at != 0 && expires_at <= env.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+
+        fact.expires_at = expires_at;
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+    }
+
+    /// Mark facts whose expiry has passed as Expired
+    /// 
+    /// Meant to be called periodically by an operator. Missing and deleted
+    /// facts are skipped, as are facts that are no longer open (including
+    /// already expired ones) and facts without an expiry or whose expiry is
+    /// still ahead, so a settled verdict is never expired away. Vote deposits still held on an expired
+    /// fact are refunded, since it will never settle.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * ids - The IDs of the facts to check
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    pub fn sweep_expired(env: Env, ids: Vec<u32>) {
+        require_not_closed(&env);
+
+        let now = env.ledger().timestamp();
+        for id in ids.iter() {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(mut fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if !fact.is_open() || fact.expires_at == 0 || now <= fact.expires_at {
+                    continue;
+                }
+                move_status_count(&env, &fact, FactStatus::Expired);
+                refund_deposits(&env, id);
+                fact.status = FactStatus::Expired;
+                fact.reopened = false;
+                env.storage().persistent().set(&fact_key, &fact);
+                extend_persistent_ttl(&env, &fact_key);
+            }
+        }
+    }
+
+    /// Hand a fact over to a new owner
+    /// 
+    /// The new owner takes over every creator-gated operation on the fact.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to transfer
+    /// * new_owner - The address that becomes the fact's creator
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    /// * If the fact doesn't exist
+    /// * If the current creator has not authorized the call
+    /// * If the new owner has voted on the fact
+    pub fn transfer_fact(env: Env, fact_id: u32, new_owner: Address) {
+        require_not_closed(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Only the current creator may transfer the fact
+        fact.creator.require_auth();
+
+        // Creators cannot vote on their own facts, so keep it that way
+        if load_votes(&env, fact_id).contains_key(new_owner.clone()) {
+            panic!("New owner has voted on this fact");
+        }
+
+        fact.creator = new_owner;
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+    }
+
+    /// Delete a fact, keeping it in storage as a tombstone
+    /// 
+    /// The fact keeps its ID and votes, stops accepting votes and is left out
+    /// of the listing functions, but `get_fact` still returns it. Vote
+    /// deposits on the fact are refunded.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to delete
+    /// 
+    /// # Panics
+    /// * If the contract is closed
+    /// * If the fact doesn't exist
+    /// * If the creator of the fact has not authorized the call
+    pub fn delete_fact(env: Env, fact_id: u32) {
+        require_not_closed(&env);
+
+        // Retrieve the fact
+        let fact_key = (FACT_PREFIX, fact_id);
+        let mut fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        // Only the creator may delete the fact
+        fact.creator.require_auth();
+
+        // Mark the fact as deleted, counting it out of its category and
+        // status and freeing its text for resubmission once
+        if !fact.deleted {
+            adjust_category_count(&env, &fact.category, false);
+            adjust_status_count(&env, fact.status, false);
+            release_content(&env, &fact.text);
+            refund_deposits(&env, fact_id);
+        }
+        fact.deleted = true;
+        env.storage().persistent().set(&fact_key, &fact);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &fact_key);
+    }
+
+    /// Report a fact as problematic without voting on its truth
+    /// 
+    /// When the flag count reaches the flag threshold a `FactFlagged` event
+    /// is published for moderators.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * reporter - The address flagging the fact
+    /// * fact_id - The ID of the fact to flag
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the reporter has already flagged the fact
+    pub fn flag_fact(env: Env, reporter: Address, fact_id: u32) {
+        // Require authorization from the reporter
+        reporter.require_auth();
+        require_not_paused(&env);
+
+        if !env.storage().persistent().has(&(FACT_PREFIX, fact_id)) {
+            panic!("Fact not found");
+        }
+
+        let flaggers_key = (FLAGGERS_PREFIX, fact_id);
+        let mut flaggers: Map<Address, bool> = env.storage()
+            .persistent()
+            .get(&flaggers_key)
+            .unwrap_or(Map::new(&env));
+        if flaggers.contains_key(reporter.clone()) {
+            panic!("Already flagged this fact");
+        }
+        flaggers.set(reporter, true);
+        env.storage().persistent().set(&flaggers_key, &flaggers);
+        extend_persistent_ttl(&env, &flaggers_key);
+
+        // Alert moderators once, as the count reaches the threshold
+        let threshold: u32 = env.storage().instance().get(&FLAG_THRESHOLD).unwrap_or(DEFAULT_FLAG_THRESHOLD);
+        if flaggers.len() == threshold {
+            FactFlagged { fact_id, flag_count: flaggers.len() }.publish(&env);
+        }
+    }
+
+    /// Add a comment to a fact's discussion thread
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * author - The address of the commenter
+    /// * fact_id - The ID of the fact to comment on
+    /// * text - The comment text
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the fact doesn't exist
+    /// * If the comment is empty or longer than `MAX_COMMENT_LEN` bytes
+    /// * If the fact already has `MAX_COMMENTS` comments
+    pub fn add_comment(env: Env, author: Address, fact_id: u32, text: String) {
+        // Require authorization from the author
+        author.require_auth();
+        require_not_paused(&env);
+
+        if !env.storage().persistent().has(&(FACT_PREFIX, fact_id)) {
+            panic!("Fact not found");
+        }
+        if text.is_empty() || text.len() > MAX_COMMENT_LEN {
+            panic!("Invalid comment length");
+        }
+
+        let comments_key = (COMMENTS_PREFIX, fact_id);
+        let mut comments: Vec<(Address, String)> = env.storage()
+            .persistent()
+            .get(&comments_key)
+            .unwrap_or(Vec::new(&env));
+        if comments.len() >= MAX_COMMENTS {
+            panic!("Comment limit reached");
+        }
+
+        comments.push_back((author, text));
+        env.storage().persistent().set(&comments_key, &comments);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &comments_key);
+    }
+
+    /// Submit a new multiple-choice poll
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The address of the poll creator
+    /// * text - The poll question
+    /// * options - The possible answers
+    /// 
+    /// # Returns
+    /// * u32 - The ID of the newly created poll
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If fewer than two options are given
+    pub fn submit_poll(env: Env, creator: Address, text: String, options: Vec<String>) -> u32 {
+        // Require authorization from the creator
+        creator.require_auth();
+        require_not_paused(&env);
+
+        if options.len() < 2 {
+            panic!("Poll needs at least two options");
+        }
+
+        // Create new poll ID (starting from 1)
+        let poll_count: u32 = env.storage().instance().get(&POLL_COUNT).unwrap_or(0);
+        let new_id = poll_count + 1;
+
+        // Start every option at zero votes
+        let mut tallies = Vec::new(&env);
+        for _ in 0..options.len() {
+            tallies.push_back(0u32);
+        }
+
+        let poll = Poll {
+            id: new_id,
+            text,
+            creator,
+            options,
+            tallies,
+            voters: Vec::new(&env),
+        };
+
+        // Store the poll and update the poll count
+        let poll_key = (POLL_PREFIX, new_id);
+        env.storage().persistent().set(&poll_key, &poll);
+        env.storage().instance().set(&POLL_COUNT, &new_id);
+
+        // Extend the TTL for the poll and counter
+        extend_persistent_ttl(&env, &poll_key);
+        extend_instance_ttl(&env);
+
+        new_id
+    }
+
+    /// Vote for one of a poll's options
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * poll_id - The ID of the poll to vote on
+    /// * option_index - The index of the chosen option
+    /// 
+    /// # Panics
+    /// * If the contract is paused
+    /// * If the poll doesn't exist
+    /// * If the option index is out of range
+    /// * If the voter has already voted on this poll
+    pub fn vote_poll(env: Env, voter: Address, poll_id: u32, option_index: u32) {
+        // Require authorization from the voter
+        voter.require_auth();
+        require_not_paused(&env);
+
+        // Retrieve the poll
+        let poll_key = (POLL_PREFIX, poll_id);
+        let mut poll: Poll = env.storage()
+            .persistent()
+            .get(&poll_key)
+            .expect("Poll not found");
+
+        let tally = poll.tallies.get(option_index).expect("Invalid option");
+
+        // Check if voter has already voted
+        let votes_key = (POLL_VOTES_PREFIX, poll_id);
+        let mut votes: Map<Address, u32> = env.storage()
+            .persistent()
+            .get(&votes_key)
+            .unwrap_or(Map::new(&env));
+        if votes.contains_key(voter.clone()) {
+            panic!("Already voted on this poll");
+        }
+
+        // Record the choice and increment the option's tally
+        votes.set(voter.clone(), option_index);
+        poll.voters.push_back(voter);
+        poll.tallies.set(option_index, tally + 1);
+
+        // Save the updated poll and votes
+        env.storage().persistent().set(&poll_key, &poll);
+        env.storage().persistent().set(&votes_key, &votes);
+
+        // Extend TTL
+        extend_persistent_ttl(&env, &poll_key);
+        extend_persistent_ttl(&env, &votes_key);
+    }
+
+    /// Get details of a specific poll
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * poll_id - The ID of the poll to retrieve
+    /// 
+    /// # Returns
+    /// * Poll - The poll with its options and tallies
+    /// 
+    /// # Panics
+    /// * If the poll doesn't exist
+    pub fn get_poll(env: Env, poll_id: u32) -> Poll {
+        let poll_key = (POLL_PREFIX, poll_id);
+        let poll: Poll = env.storage()
+            .persistent()
+            .get(&poll_key)
+            .expect("Poll not found");
+
+        // Extend TTL on read
+        extend_persistent_ttl(&env, &poll_key);
+
+        poll
+    }
+
+    /// Get details of a specific fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to retrieve
+    /// 
+    /// # Returns
+    /// * Fact - The fact details
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_fact(env: Env, fact_id: u32) -> Fact {
+        let fact_key = (FACT_PREFIX, fact_id);
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+        
+        // Extend TTL on read
+        extend_persistent_ttl(&env, &fact_key);
+        
+        fact
+    }
+
+    /// Get details of a specific fact without extending its TTL
+    /// 
+    /// Unlike `get_fact` this leaves storage untouched, which keeps frequent
+    /// polling cheap.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to retrieve
+    /// 
+    /// # Returns
+    /// * Fact - The fact details
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn peek_fact(env: Env, fact_id: u32) -> Fact {
+        env.storage()
+            .persistent()
+            .get(&(FACT_PREFIX, fact_id))
+            .expect("Fact not found")
+    }
+
+    /// Check whether a fact has been verified
+    /// 
+    /// Meant for other contracts gating logic on a fact's verdict, so it
+    /// returns false instead of panicking when the fact doesn't exist.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * bool - True if the fact exists and its status is Verified
+    pub fn is_verified(env: Env, fact_id: u32) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, Fact>(&(FACT_PREFIX, fact_id))
+            .is_some_and(|fact| fact.status == FactStatus::Verified)
+    }
+
+    /// Get details of a fact that may not exist
+    /// 
+    /// Unlike `get_fact` this neither panics nor extends the fact's TTL, so
+    /// it suits batch and speculative reads. (The generated client already
+    /// has a `try_get_fact`, hence the name.)
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact to retrieve
+    /// 
+    /// # Returns
+    /// * Option<Fact> - The fact details, or None if there is no such fact
+    pub fn get_fact_or_none(env: Env, fact_id: u32) -> Option<Fact> {
+        env.storage().persistent().get(&(FACT_PREFIX, fact_id))
+    }
+
+    /// Get the number of addresses that voted on a fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * u32 - The number of voters
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_voter_count(env: Env, fact_id: u32) -> u32 {
+        let fact_key = (FACT_PREFIX, fact_id);
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        fact.voters.len()
+    }
+
+    /// Check whether an address has voted on a fact
+    /// 
+    /// Abstentions count as votes, and anonymous facts are checked through
+    /// the voter's nullifier.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// * voter - The address to check
+    /// 
+    /// # Returns
+    /// * bool - True if the address has voted, false otherwise or if the fact
+    ///   doesn't exist
+    pub fn has_voted(env: Env, fact_id: u32, voter: Address) -> bool {
+        let Some(fact) = env.storage().persistent().get::<_, Fact>(&(FACT_PREFIX, fact_id)) else {
+            return false;
+        };
+
+        has_voted_on(&env, &fact, &voter)
+    }
+
+    /// Get the choice an address made on a fact
+    /// 
+    /// Abstentions, and votes on anonymous facts, have no recorded choice.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// * voter - The address to look up
+    /// 
+    /// # Returns
+    /// * Option<bool> - The direction of the address's vote, or None if it
+    ///   hasn't voted or the fact doesn't exist
+    pub fn get_voter_choice(env: Env, fact_id: u32, voter: Address) -> Option<bool> {
+        load_votes(&env, fact_id).get(voter)
+    }
+
+    /// Get the weight a voter's vote was counted with
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// * voter - The address that voted
+    /// 
+    /// # Returns
+    /// * u32 - The stake for staked votes, otherwise the voter's token balance
+    ///   snapshot under balance weighting, or one plus their capped
+    ///   reputation at the time of voting
+    /// 
+    /// # Panics
+    /// * If the voter hasn't voted on the fact
+    pub fn get_vote_weight(env: Env, fact_id: u32, voter: Address) -> u32 {
+        load_weights(&env, fact_id)
+            .get(voter)
+            .expect("Not voted on this fact")
+    }
+
+    /// Get the reason codes voters gave on a fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * Vec<(Address, Symbol)> - (voter, reason) pairs for every vote cast
+    ///   with `vote_with_reason`
+    pub fn get_vote_reasons(env: Env, fact_id: u32) -> Vec<(Address, Symbol)> {
+        let mut reasons = Vec::new(&env);
+        for entry in load_reasons(&env, fact_id).iter() {
+            reasons.push_back(entry);
+        }
+
+        reasons
+    }
+
+    /// Get the addresses that voted a given way on a fact
+    /// 
+    /// Abstentions and votes on anonymous facts aren't linked to a direction,
+    /// so they never appear.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// * is_true - True for addresses that voted "true", false for "false"
+    /// 
+    /// # Returns
+    /// * Vec<Address> - The matching voters, or an empty list if there are none
+    pub fn get_voters_by_direction(env: Env, fact_id: u32, is_true: bool) -> Vec<Address> {
+        let mut voters = Vec::new(&env);
+        for (voter, direction) in load_votes(&env, fact_id).iter() {
+            if direction == is_true {
+                voters.push_back(voter);
+            }
+        }
+
+        voters
+    }
+
+    /// Get a fact's core fields without its creator or voter list
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * FactSummary - The fact's id, text, vote counts and status
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_fact_summary(env: Env, fact_id: u32) -> FactSummary {
+        let fact_key = (FACT_PREFIX, fact_id);
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        FactSummary {
+            id: fact.id,
+            text: fact.text,
+            true_votes: fact.true_votes,
+            false_votes: fact.false_votes,
+            status: fact.status,
+        }
+    }
+
+    /// Get a fact's status together with how decisive the tally is
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * (FactStatus, u32) - The status and the absolute difference between
+    ///   true and false votes, which is zero for a tie
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_result(env: Env, fact_id: u32) -> (FactStatus, u32) {
+        let fact_key = (FACT_PREFIX, fact_id);
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        (fact.status, fact.true_votes.abs_diff(fact.false_votes))
+    }
+
+    /// Get the vote tallies of several facts in one call
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * ids - The IDs of the facts to look up
+    /// 
+    /// # Returns
+    /// * Vec<(u32, u32, u32)> - (fact_id, true_votes, false_votes) for each
+    ///   requested fact, in request order, skipping IDs that don't exist
+    pub fn get_tallies(env: Env, ids: Vec<u32>) -> Vec<(u32, u32, u32)> {
+        let mut tallies = Vec::new(&env);
+
+        for id in ids.iter() {
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&(FACT_PREFIX, id)) {
+                tallies.push_back((id, fact.true_votes, fact.false_votes));
+            }
+        }
+
+        tallies
+    }
+
+    /// Get the consensus for a fact as a percentage of true votes
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * (u32, u64) - The true-vote percentage (0-100) and the total vote count,
+    ///   or (0, 0) if the fact has no votes
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_consensus(env: Env, fact_id: u32) -> (u32, u64) {
+        let fact_key = (FACT_PREFIX, fact_id);
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&fact_key)
+            .expect("Fact not found");
+
+        let total = fact.total_votes();
+        if total == 0 {
+            return (0, 0);
+        }
+
+        ((fact.true_votes as u64 * 100 / total) as u32, total)
+    }
+
+    /// Get the true and false votes cast on a fact since a given time
+    /// 
+    /// Sums vote weights from the fact's vote timeline. Retracted votes drop
+    /// out of the timeline, and a changed vote counts in its new direction
+    /// from the time it was changed.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// * since - The earliest vote timestamp to count
+    /// 
+    /// # Returns
+    /// * (u32, u32) - The weighted true and false votes cast at or after `since`
+    pub fn get_tally_since(env: Env, fact_id: u32, since: u64) -> (u32, u32) {
+        let mut true_votes = 0;
+        let mut false_votes = 0;
+        for (timestamp, _, is_true, weight) in load_timeline(&env, fact_id).iter() {
+            if timestamp < since {
+                continue;
+            }
+            if is_true {
+                true_votes = add_votes(true_votes, weight);
+            } else {
+                false_votes = add_votes(false_votes, weight);
+            }
+        }
+
+        (true_votes, false_votes)
+    }
+
+    /// Get a fact's tally broken down by voter reputation tier
+    /// 
+    /// Voters are bucketed by their current, decayed reputation: `new` with none yet,
+    /// `expert` at `EXPERT_REPUTATION` or more, and `established` in between.
+    /// Counts are ballots rather than weights. Anonymous votes aren't tied
+    /// to an address, so they don't appear.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * Vec<(Symbol, u32, u32)> - (tier, true votes, false votes) for the new,
+    ///   established and expert tiers in that order
+    pub fn get_tally_by_tier(env: Env, fact_id: u32) -> Vec<(Symbol, u32, u32)> {
+        // (true, false) counts for the new, established and expert tiers
+        let mut tallies = [(0u32, 0u32); 3];
+        for (voter, is_true) in load_votes(&env, fact_id).iter() {
+            let score = decayed_reputation(&env, &voter);
+            let tier = if score <= 0 {
+                0
+            } else if score < EXPERT_REPUTATION {
+                1
+            } else {
+                2
+            };
+            if is_true {
+                tallies[tier].0 += 1;
+            } else {
+                tallies[tier].1 += 1;
+            }
+        }
+
+        let mut result = Vec::new(&env);
+        for (name, (true_votes, false_votes)) in ["new", "established", "expert"].into_iter().zip(tallies) {
+            result.push_back((Symbol::new(&env, name), true_votes, false_votes));
+        }
+        result
+    }
+
+    /// Get how contested a fact is
+    /// 
+    /// The score is `4 * true * false / (true + false)`, which equals the
+    /// total vote count for an even split and shrinks towards zero as the
+    /// tally becomes one-sided, so large evenly split facts score highest.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * u64 - The controversy score, or 0 if the fact has no votes
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_controversy(env: Env, fact_id: u32) -> u64 {
+        let fact: Fact = env.storage()
+            .persistent()
+            .get(&(FACT_PREFIX, fact_id))
+            .expect("Fact not found");
+
+        let total = fact.total_votes() as u128;
+        if total == 0 {
+            return 0;
+        }
+
+        (4 * fact.true_votes as u128 * fact.false_votes as u128 / total) as u64
+    }
+
+    /// Get all active facts stored in the contract
+    /// 
+    /// Deleted and expired facts are left out.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - A vector containing all facts
+    pub fn get_all_facts(env: Env) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        // Iterate through all fact IDs and collect them
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.deleted || fact.status == FactStatus::Expired {
+                    continue;
+                }
+                facts.push_back(fact);
+                // Extend TTL
+                extend_persistent_ttl(&env, &fact_key);
+            }
+        }
+
+        facts
+    }
+
+    /// Get a page of facts starting at a given ID
+    /// 
+    /// Missing IDs are skipped, and the TTL of returned facts is not
+    /// extended so that browsing stays cheap.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * start_id - The first fact ID to include
+    /// * limit - The maximum number of facts to return
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - Up to `limit` facts in ascending ID order
+    pub fn get_facts_paged(env: Env, start_id: u32, limit: u32) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        for id in start_id.max(1)..=fact_count {
+            if facts.len() >= limit {
+                break;
+            }
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if !fact.deleted {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Get the most recently submitted facts
+    /// 
+    /// Only the last `count` IDs are read, so this is cheaper than
+    /// `get_all_facts`. Deleted and missing IDs are skipped rather than
+    /// replaced, and TTLs are not extended.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * count - How many of the latest IDs to look at
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - The facts in descending ID order
+    pub fn get_recent_facts(env: Env, count: u32) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        for id in (fact_count.saturating_sub(count) + 1..=fact_count).rev() {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if !fact.deleted {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Rank facts for a trending view, favouring recent and heavily voted ones
+    /// 
+    /// Each fact scores `(votes + 1) * RANK_SCALE / (age_in_hours + 2)`, so a
+    /// fact's score decays as it ages. Deleted facts are left out.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * now - The timestamp to measure fact ages against
+    /// 
+    /// # Returns
+    /// * Vec<(u32, u64)> - (fact_id, score) pairs, highest score first, ties
+    ///   broken by ascending ID
+    pub fn get_facts_ranked(env: Env, now: u64) -> Vec<(u32, u64)> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut ranked: Vec<(u32, u64)> = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.deleted {
+                    continue;
+                }
+                let votes = fact.total_votes();
+                let age_hours = now.saturating_sub(fact.created_at) / 3600;
+                let score = (votes + 1) * RANK_SCALE / (age_hours + 2);
+
+                // Insert after every entry with an equal or higher score
+                let mut index = ranked.len();
+                while index > 0 && ranked.get_unchecked(index - 1).1 < score {
+                    index -= 1;
+                }
+                ranked.insert(index, (id, score));
+            }
+        }
+
+        ranked
+    }
+
+    /// Get the facts with the fewest votes, for review prompts
+    /// 
+    /// Deleted facts are left out.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * count - The maximum number of facts to return
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - Up to `count` facts, fewest true and false votes first,
+    ///   ties broken by ascending ID
+    pub fn get_least_voted_facts(env: Env, count: u32) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts: Vec<Fact> = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.deleted {
+                    continue;
+                }
+                let votes = fact.total_votes();
+
+                // Insert after every entry with an equal or lower vote count
+                let mut index = facts.len();
+                while index > 0 {
+                    let previous = facts.get_unchecked(index - 1);
+                    if previous.total_votes() <= votes {
+                        break;
+                    }
+                    index -= 1;
+                }
+                if index < count {
+                    facts.insert(index, fact);
+                }
+
+                // Keep only the least-voted entries
+                if facts.len() > count {
+                    facts.pop_back();
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Get all facts in a given category
+    /// 
+    /// This scans every stored fact, so its cost grows linearly with the
+    /// total number of facts. TTLs are not extended during the scan.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * category - The category to filter by
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - The matching facts in ascending ID order
+    pub fn get_facts_by_category(env: Env, category: Symbol) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.category == category && !fact.deleted {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Get all facts with a given status
+    /// 
+    /// This scans every stored fact without extending TTLs.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * status - The status to filter by
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - The matching facts in ascending ID order, empty if none match
+    pub fn get_facts_by_status(env: Env, status: FactStatus) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.status == status && !fact.deleted {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Search facts for a substring of their text
+    /// 
+    /// Matching is case-sensitive and byte-wise. This scans every stored fact,
+    /// an O(n) walk over the whole set, without extending TTLs.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * query - The text to look for; an empty query matches every fact
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - The matching facts in ascending ID order
+    pub fn search_facts(env: Env, query: String) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let needle = query.to_bytes();
+        let mut facts = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if !fact.deleted && bytes_contains(&fact.text.to_bytes(), &needle) {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Get the number of live facts in a category
+    /// 
+    /// This reads a counter kept up to date on submission and deletion, so
+    /// it doesn't scan the facts.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * category - The category to count
+    /// 
+    /// # Returns
+    /// * u32 - The number of facts in the category that haven't been deleted
+    pub fn get_category_count(env: Env, category: Symbol) -> u32 {
+        env.storage()
+            .instance()
+            .get::<_, Map<Symbol, u32>>(&CATEGORY_COUNTS)
+            .and_then(|counts| counts.get(category))
+            .unwrap_or(0)
+    }
+
+    /// Get the number of live facts in each status
+    /// 
+    /// This reads counters kept up to date as facts are submitted, settled,
+    /// challenged, expired and deleted, so it doesn't scan the facts.
+    /// Reopened facts count as Disputed. Facts stored before the counters
+    /// existed aren't included.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * (u32, u32, u32, u32, u32) - The Open, Verified, Debunked, Disputed
+    ///   and Expired counts
+    pub fn get_status_counts(env: Env) -> (u32, u32, u32, u32, u32) {
+        let counts: Map<FactStatus, u32> = env.storage()
+            .instance()
+            .get(&STATUS_COUNTS)
+            .unwrap_or(Map::new(&env));
+        let count = |status| counts.get(status).unwrap_or(0);
+        (
+            count(FactStatus::Open),
+            count(FactStatus::Verified),
+            count(FactStatus::Debunked),
+            count(FactStatus::Disputed),
+            count(FactStatus::Expired),
+        )
+    }
+
+    /// Get all facts submitted by a given creator
+    /// 
+    /// This scans every stored fact without extending TTLs.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * creator - The creator address to filter by
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - The creator's facts in ascending ID order
+    pub fn get_facts_by_creator(env: Env, creator: Address) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.creator == creator && !fact.deleted {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Get the facts created within a time window
+    /// 
+    /// This scans every stored fact without extending TTLs. Deleted facts
+    /// are left out.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * start_ts - The earliest creation timestamp to include
+    /// * end_ts - The latest creation timestamp to include
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - The matching facts in ascending ID order
+    pub fn get_facts_between(env: Env, start_ts: u64, end_ts: u64) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let mut facts = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if !fact.deleted && fact.created_at >= start_ts && fact.created_at <= end_ts {
+                    facts.push_back(fact);
+                }
+            }
+        }
+
+        facts
+    }
+
+    /// Get the creators with the most verified facts
+    /// 
+    /// This scans every stored fact without extending TTLs. Deleted facts
+    /// don't count.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * limit - The maximum number of creators to return
+    /// 
+    /// # Returns
+    /// * Vec<(Address, u32)> - (creator, verified_count) pairs, highest count
+    ///   first, ties in address order
+    pub fn get_top_creators(env: Env, limit: u32) -> Vec<(Address, u32)> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+
+        // Count verified facts per creator
+        let mut counts: Map<Address, u32> = Map::new(&env);
+        for id in 1..=fact_count {
+            let fact_key = (FACT_PREFIX, id);
+            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
+                if fact.status == FactStatus::Verified && !fact.deleted {
+                    counts.set(fact.creator.clone(), counts.get(fact.creator).unwrap_or(0) + 1);
+                }
+            }
+        }
+
+        // Sort by count, inserting after every entry with an equal or higher count
+        let mut leaderboard: Vec<(Address, u32)> = Vec::new(&env);
+        for (creator, count) in counts.iter() {
+            let mut index = leaderboard.len();
+            while index > 0 && leaderboard.get_unchecked(index - 1).1 < count {
+                index -= 1;
+            }
+            leaderboard.insert(index, (creator, count));
+        }
+
+        // Keep only the top entries
+        while leaderboard.len() > limit {
+            leaderboard.pop_back();
+        }
+
+        leaderboard
+    }
+
+    /// Get the IDs of the facts an address has voted on
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// 
+    /// # Returns
+    /// * Vec<u32> - Fact IDs in the order the votes were cast
+    pub fn get_voter_history(env: Env, voter: Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&(HISTORY_PREFIX, voter))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get open facts an address could still vote on
+    /// 
+    /// This scans stored facts in ascending ID order without extending TTLs,
+    /// stopping once `limit` facts are found. Facts whose voting window has
+    /// closed, facts the address created, and facts it has already voted or
+    /// abstained on are left out.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// * limit - The maximum number of facts to return
+    /// 
+    /// # Returns
+    /// * Vec<Fact> - Up to `limit` facts in ascending ID order
+    pub fn get_unvoted_facts(env: Env, voter: Address, limit: u32) -> Vec<Fact> {
+        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
+        let now = env.ledger().timestamp();
+        let mut facts = Vec::new(&env);
+
+        for id in 1..=fact_count {
+            if facts.len() >= limit {
+                break;
+            }
+            let Some(fact) = env.storage().persistent().get::<_, Fact>(&(FACT_PREFIX, id)) else {
+                continue;
+            };
+            if !fact.is_open() || now > fact.voting_ends_at || fact.creator == voter {
+                continue;
+            }
+            if has_voted_on(&env, &fact, &voter) {
+                continue;
+            }
+            facts.push_back(fact);
+        }
+
+        facts
+    }
+
+    /// Get the number of facts an address has voted on
+    /// 
+    /// Reads the voter's history, so votes on anonymous facts aren't counted.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * voter - The address of the voter
+    /// 
+    /// # Returns
+    /// * u32 - The number of facts with a recorded vote from the address
+    pub fn get_vote_count_by(env: Env, voter: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, Vec<u32>>(&(HISTORY_PREFIX, voter))
+            .map_or(0, |history| history.len())
+    }
+
+    /// Get the number of users who flagged a fact
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * u32 - The number of distinct reporters
+    pub fn get_flag_count(env: Env, fact_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, Map<Address, bool>>(&(FLAGGERS_PREFIX, fact_id))
+            .map_or(0, |flaggers| flaggers.len())
+    }
+
+    /// Get a fact's comment thread
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * Vec<(Address, String)> - (author, text) pairs in the order they were added
+    pub fn get_comments(env: Env, fact_id: u32) -> Vec<(Address, String)> {
+        env.storage()
+            .persistent()
+            .get(&(COMMENTS_PREFIX, fact_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the texts a fact had before it was edited
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// * fact_id - The ID of the fact
+    /// 
+    /// # Returns
+    /// * Vec<String> - Prior texts, oldest first
+    /// 
+    /// # Panics
+    /// * If the fact doesn't exist
+    pub fn get_fact_history(env: Env, fact_id: u32) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get::<_, Fact>(&(FACT_PREFIX, fact_id))
+            .expect("Fact not found")
+            .history
+    }
+
+    /// Get the total number of votes cast across all facts
+    /// 
+    /// This is a running counter maintained as votes are cast and retracted,
+    /// so it is cheap to read. Staked votes count by their weight. Removing a
+    /// fact or resetting its votes takes them back out, and votes merged
+    /// into another fact are only counted once.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * u64 - The sum of all true and false votes
+    pub fn get_total_votes(env: Env) -> u64 {
+        env.storage().instance().get(&TOTAL_VOTES).unwrap_or(0)
+    }
+
+    /// Get the vote fees and forfeited deposits collected into the treasury
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * i128 - The total collected, in the stake token
+    pub fn get_treasury_balance(env: Env) -> i128 {
+        env.storage().instance().get(&TREASURY).unwrap_or(0)
+    }
+
+    /// Get the total number of facts
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * u32 - The total count of facts
+    pub fn get_fact_count(env: Env) -> u32 {
+        env.storage().instance().get(&FACT_COUNT).unwrap_or(0)
+    }
+
+    /// Get a snapshot of the contract's aggregate metrics
+    /// 
+    /// Every figure comes from a running counter, so this doesn't scan the
+    /// facts. Unique voters are addresses that have voted or abstained at
+    /// least once; anonymous votes aren't counted, and neither are votes
+    /// cast before the counter existed.
+    /// 
+    /// # Arguments
+    /// * env - The Soroban environment
+    /// 
+    /// # Returns
+    /// * Stats - Fact and vote totals, live facts per status and unique voters
+    pub fn get_stats(env: Env) -> Stats {
+        let (open, verified, debunked, disputed, expired) = Self::get_status_counts(env.clone());
+        Stats {
+            total_facts: Self::get_fact_count(env.clone()),
+            total_votes: Self::get_total_votes(env.clone()),
+            open,
+            verified,
+            debunked,
+            disputed,
+            expired,
+            unique_voters: env.storage().instance().get(&UNIQUE_VOTERS).unwrap_or(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{
+        testutils::{storage::Persistent as _, Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+        token, vec, Env, IntoVal,
+    };
+
+    // Voting window used by tests that don't exercise the deadline
+    const VOTING_PERIOD: u64 = 86_400;
+    // Category used by tests that don't exercise filtering
+    const CATEGORY: Symbol = symbol_short!("general");
+    // Reveal window used by tests of sealed facts
+    const REVEAL_PERIOD: u64 = 3_600;
+
+    // Downstream contract that pays out only for verified facts
+    #[contract]
+    struct PayoutContract;
+
+    #[contractimpl]
+    impl PayoutContract {
+        pub fn pays_out(env: Env, oracle: Address, fact_id: u32) -> bool {
+            FactVerificationContractClient::new(&env, &oracle).is_verified(&fact_id)
+        }
+    }
+
+    // Integrator contract that records the finalizations it's notified of
+    #[contract]
+    struct ListenerContract;
+
+    #[contractimpl]
+    impl ListenerContract {
+        pub fn on_final(env: Env, fact_id: u32, status: FactStatus) {
+            let mut seen: Vec<(u32, FactStatus)> = env.storage().instance().get(&symbol_short!("SEEN")).unwrap_or(Vec::new(&env));
+            seen.push_back((fact_id, status));
+            env.storage().instance().set(&symbol_short!("SEEN"), &seen);
+        }
+
+        pub fn on_fail(_env: Env, _fact_id: u32, _status: FactStatus) {
+            panic!("Listener failed");
+        }
+
+        pub fn seen(env: Env) -> Vec<(u32, FactStatus)> {
+            env.storage().instance().get(&symbol_short!("SEEN")).unwrap_or(Vec::new(&env))
+        }
+    }
+
+    // Overwrite a stored fact's true-vote counter, bypassing the voting rules
+    fn set_true_votes(env: &Env, contract_id: &Address, fact_id: u32, true_votes: u32) {
+        env.as_contract(contract_id, || {
+            let fact_key = (FACT_PREFIX, fact_id);
+            let mut fact: Fact = env.storage().persistent().get(&fact_key).unwrap();
+            fact.true_votes = true_votes;
+            env.storage().persistent().set(&fact_key, &fact);
+        });
+    }
+
+    // Sign a vote the way `submit_votes_signed` expects
+    fn sign_vote(env: &Env, key: &SigningKey, contract: &Address, fact_id: u32, is_true: bool, nonce: u64) -> BytesN<64> {
+        let message = (contract.clone(), fact_id, is_true, nonce).to_xdr(env);
+        let mut buf = [0u8; 128];
+        let len = message.len() as usize;
+        message.copy_into_slice(&mut buf[..len]);
+        BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes())
+    }
+
+    // Register a voter's signing key with the contract
+    fn register_key(env: &Env, client: &FactVerificationContractClient, voter: &Address, key: &SigningKey) {
+        client.register_vote_key(voter, &BytesN::from_array(env, &key.verifying_key().to_bytes()));
+    }
+
+    // Fact text ending in a number, since identical texts are rejected as duplicates
+    fn numbered_text(env: &Env, prefix: &str, n: u32) -> String {
+        let mut buf = [0u8; 64];
+        buf[..prefix.len()].copy_from_slice(prefix.as_bytes());
+        let mut len = prefix.len();
+        buf[len] = b' ';
+        len += 1;
+        for exp in (0..n.checked_ilog10().unwrap_or(0) + 1).rev() {
+            buf[len] = b'0' + (n / 10u32.pow(exp) % 10) as u8;
+            len += 1;
+        }
+        String::from_bytes(env, &buf[..len])
+    }
+
+    // Move the ledger past the voting window of every fact submitted so far
+    fn end_voting(env: &Env) {
+        env.ledger().set_timestamp(env.ledger().timestamp() + VOTING_PERIOD + 1);
+    }
+
+    #[test]
+    fn test_initialize() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+
+        // The admin has to authorize taking the role
+        assert!(client.try_initialize(&admin).is_err());
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+
+        assert_eq!(client.get_admin(), admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already initialized")]
+    fn test_initialize_twice() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+        env.mock_all_auths();
+
+        client.initialize(&Address::generate(&env));
+
+        // Second initialization - should panic
+        client.initialize(&Address::generate(&env));
+    }
+
+    #[test]
+    fn test_initialize_if_needed() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        assert!(client.try_initialize_if_needed(&admin).is_err());
+
+        env.mock_all_auths();
+        assert!(client.initialize_if_needed(&admin));
+
+        // A repeated call is a no-op rather than a panic
+        assert!(!client.initialize_if_needed(&Address::generate(&env)));
+        assert_eq!(client.get_admin(), admin);
+    }
+
+    #[test]
+    fn test_set_admin() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_admin(&new_admin);
+
+        // The handover is recorded under the outgoing admin
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("set_admin")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(client.get_admin(), new_admin);
+    }
+
+    #[test]
+    fn test_get_config() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let token = Address::generate(&env);
+        let reward_token = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // Unset values read as their defaults
+        let defaults = client.get_config();
+        assert_eq!(defaults.admin, Some(admin.clone()));
+        assert!(!defaults.paused);
+        assert!(!defaults.closed);
+        assert_eq!(defaults.token, None);
+        assert_eq!(defaults.finalize_mode, FinalizeMode::Anyone);
+        assert_eq!(defaults.ttl_bump, DEFAULT_TTL_BUMP);
+
+        let categories = vec![&env, symbol_short!("science")];
+        client.set_paused(&true);
+        client.set_token(&token);
+        client.set_submission_gate(&token, &10);
+        client.set_submit_cooldown(&60);
+        client.set_vote_cooldown(&30);
+        client.set_allowed_categories(&categories);
+        client.set_min_quorum(&5);
+        client.set_supermajority(&75);
+        client.set_category_thresholds(&symbol_short!("science"), &10, &90);
+        client.set_rep_floor(&3);
+        client.set_auto_verify_threshold(&20);
+        client.set_flag_threshold(&8);
+        client.set_finalize_mode(&FinalizeMode::AdminOnly);
+        client.set_tie_policy(&TiePolicy::ExtendVoting, &3_600);
+        client.set_balance_weighting(&true);
+        client.set_vote_deposit(&5);
+        client.set_vote_fee(&2);
+        client.set_creator_reward(&reward_token, &50);
+        client.set_finalize_callback(&token, &symbol_short!("notify"), &true);
+        client.set_ttl_bump(&1_000);
+        client.close();
+
+        assert_eq!(client.get_config(), Config {
+            admin: Some(admin),
+            paused: true,
+            closed: true,
+            token: Some(token.clone()),
+            gate_token: Some(token.clone()),
+            gate_min_balance: 10,
+            submit_cooldown: 60,
+            vote_cooldown: 30,
+            allowed_categories: categories,
+            min_quorum: 5,
+            supermajority: 75,
+            category_thresholds: Map::from_array(&env, [(symbol_short!("science"), (10, 90))]),
+            rep_floor: 3,
+            auto_verify_threshold: 20,
+            flag_threshold: 8,
+            finalize_mode: FinalizeMode::AdminOnly,
+            tie_policy: TiePolicy::ExtendVoting,
+            tie_extension: 3_600,
+            balance_weighting: true,
+            vote_deposit: 5,
+            vote_fee: 2,
+            reward_token: Some(reward_token),
+            reward_amount: 50,
+            callback_contract: Some(token),
+            callback_function: Some(symbol_short!("notify")),
+            callback_tolerates_failure: true,
+            ttl_bump: 1_000,
+        });
+    }
+
+    #[test]
+    fn test_pause_and_unpause() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+
+        client.set_paused(&true);
+
+        // Pausing is recorded as a contract-wide moderation event
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("pause")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert!(client.is_paused());
+
+        // Submissions are rejected while paused
+        let result = client.try_submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
+        assert!(result.is_err());
+
+        // Reads still work while paused
+        assert_eq!(client.get_fact(&fact_id).id, fact_id);
+        assert_eq!(client.get_fact_count(), 1);
+
+        // Submissions succeed again once unpaused
+        client.set_paused(&false);
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("unpause")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert!(!client.is_paused());
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(second_id, 2);
+    }
+
+    #[test]
+    fn test_close() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &fact_id, &true);
+
+        client.close();
+
+        // Closing is recorded as a contract-wide moderation event
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("close")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert!(client.is_closed());
+
+        // Reads still work once closed
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(client.get_all_facts().len(), 1);
+        assert_eq!(client.get_admin(), admin);
+        assert!(client.has_voted(&fact_id, &voter));
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract closed")]
+    fn test_submit_after_close() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.close();
+
+        client.submit_fact(&Address::generate(&env), &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract closed")]
+    fn test_vote_after_close() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&Address::generate(&env), &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        client.close();
+
+        client.vote(&Address::generate(&env), &fact_id, &true);
+    }
+
+    #[test]
+    fn test_mutations_after_close() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        client.close();
+
+        // Admin actions and fact lifecycle calls are rejected alike
+        assert!(client.try_set_paused(&true).is_err());
+        assert!(client.try_finalize_fact(&fact_id).is_err());
+        assert!(client.try_delete_fact(&fact_id).is_err());
+        assert!(client.try_bump_ttl(&vec![&env, fact_id]).is_err());
+        assert!(client.try_close().is_err());
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Open);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_vote_while_paused() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        client.set_paused(&true);
+
+        // Voting while paused - should panic
+        client.vote(&Address::generate(&env), &fact_id, &true);
+    }
+
+    #[test]
+    fn test_submit_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "The Earth is round");
+
+        // Mock the authorization
+        env.mock_all_auths();
+
+        // Submit a fact
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        assert_eq!(fact_id, 1);
+
+        // Verify the fact was stored correctly
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.id, 1);
+        assert_eq!(fact.text, fact_text);
+        assert_eq!(fact.creator, creator);
+        assert_eq!(fact.true_votes, 0);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.voters.len(), 0);
+        assert_eq!(fact.status, FactStatus::Open);
+        assert_eq!(fact.category, CATEGORY);
+        assert_eq!(fact.challengers.len(), 0);
+        assert!(!fact.reopened);
+        assert!(!fact.sealed);
+    }
+
+    #[test]
+    fn test_submit_fact_with_source() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "The Great Wall is not visible from space");
+        let source = String::from_str(&env, "https://www.nasa.gov/great-wall");
+
+        env.mock_all_auths();
+
+        let sourced_id = client.submit_fact_with_source(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD, &source);
+        let plain_id = client.submit_fact(&creator, &String::from_str(&env, "The Moon has no atmosphere to speak of"), &CATEGORY, &VOTING_PERIOD);
+
+        // The source round-trips through storage
+        assert_eq!(client.get_fact(&sourced_id).source, source);
+
+        // Facts submitted without a source store an empty string
+        assert_eq!(client.get_fact(&plain_id).source, String::from_str(&env, ""));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vote count overflow")]
+    fn test_vote_count_overflow() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Overflowing fact"), &CATEGORY, &VOTING_PERIOD);
+        set_true_votes(&env, &contract_id, fact_id, u32::MAX);
+
+        // One more true vote doesn't fit in the counter - should panic
+        client.vote(&Address::generate(&env), &fact_id, &true);
+    }
+
+    #[test]
+    fn test_vote_cap() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let capped_id = client.submit_capped_fact(&creator, &String::from_str(&env, "Capped fact"), &CATEGORY, &VOTING_PERIOD, &2);
+        client.vote(&Address::generate(&env), &capped_id, &true);
+        client.vote(&Address::generate(&env), &capped_id, &false);
+
+        // The cap has been reached
+        let result = client.try_vote(&Address::generate(&env), &capped_id, &true);
+        assert!(result.is_err());
+        assert_eq!(client.get_fact(&capped_id).voters.len(), 2);
+
+        // A zero cap never fills up
+        let uncapped_id = client.submit_capped_fact(&creator, &String::from_str(&env, "Uncapped fact"), &CATEGORY, &VOTING_PERIOD, &0);
+        for _ in 0..5 {
+            client.vote(&Address::generate(&env), &uncapped_id, &true);
+        }
+        assert_eq!(client.get_fact(&uncapped_id).true_votes, 5);
+        assert_eq!(client.get_fact(&uncapped_id).status, FactStatus::Open);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vote cap reached")]
+    fn test_vote_over_cap() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_capped_fact(&creator, &String::from_str(&env, "Capped fact"), &CATEGORY, &VOTING_PERIOD, &1);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+
+        // One vote past the cap - should panic
+        client.vote(&Address::generate(&env), &fact_id, &true);
+    }
+
+    #[test]
+    fn test_allowed_categories() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // Without a whitelist any category is accepted
+        client.submit_fact(&creator, &String::from_str(&env, "Unrestricted fact"), &symbol_short!("gossip"), &VOTING_PERIOD);
+
+        client.set_allowed_categories(&vec![&env, symbol_short!("science"), symbol_short!("health")]);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Approved fact"), &symbol_short!("science"), &VOTING_PERIOD);
+        assert_eq!(client.get_fact(&fact_id).category, symbol_short!("science"));
+
+        // Clearing the whitelist allows everything again
+        client.set_allowed_categories(&Vec::new(&env));
+        client.submit_fact(&creator, &String::from_str(&env, "Unrestricted again"), &symbol_short!("gossip"), &VOTING_PERIOD);
+    }
+
+    #[test]
+    #[should_panic(expected = "Category not allowed")]
+    fn test_disallowed_category() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_allowed_categories(&vec![&env, symbol_short!("science")]);
+
+        // Submit under a category outside the whitelist - should panic
+        client.submit_fact(&creator, &String::from_str(&env, "Off-topic fact"), &symbol_short!("gossip"), &VOTING_PERIOD);
+    }
+
+    #[test]
+    fn test_submit_fact_for() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Relayed fact text");
+
+        let submit_invoke = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "submit_fact_for",
+            args: (&creator, &relayer, &fact_text, CATEGORY, VOTING_PERIOD).into_val(&env),
+            sub_invokes: &[],
+        };
+
+        // The creator alone can't submit through the relayer
+        env.mock_auths(&[MockAuth { address: &creator, invoke: &submit_invoke }]);
+        assert!(client.try_submit_fact_for(&creator, &relayer, &fact_text, &CATEGORY, &VOTING_PERIOD).is_err());
+
+        // Nor can the relayer alone
+        env.mock_auths(&[MockAuth { address: &relayer, invoke: &submit_invoke }]);
+        assert!(client.try_submit_fact_for(&creator, &relayer, &fact_text, &CATEGORY, &VOTING_PERIOD).is_err());
+
+        // With both authorizations the fact belongs to the creator
+        env.mock_auths(&[
+            MockAuth { address: &creator, invoke: &submit_invoke },
+            MockAuth { address: &relayer, invoke: &submit_invoke },
+        ]);
+        let fact_id = client.submit_fact_for(&creator, &relayer, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.creator, creator);
+        assert_eq!(fact.relayer, Some(relayer));
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate fact")]
+    fn test_submit_duplicate_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C at sea level"), &CATEGORY, &VOTING_PERIOD);
+
+        // The same claim with different case and spacing - should panic
+        client.submit_fact(&Address::generate(&env), &String::from_str(&env, "  water boils at 100c\tat sea  level "), &CATEGORY, &VOTING_PERIOD);
+    }
+
+    #[test]
+    fn test_distinct_facts_get_distinct_ids() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C at sea level"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 90C at altitude"), &CATEGORY, &VOTING_PERIOD);
+        assert_ne!(first_id, second_id);
+
+        // Deleting a fact frees its text for resubmission
+        client.delete_fact(&first_id);
+        let resubmitted_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C at sea level"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(resubmitted_id, second_id + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fact text too short")]
+    fn test_submit_empty_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Submit an empty fact - should panic
+        client.submit_fact(&creator, &String::from_str(&env, ""), &CATEGORY, &VOTING_PERIOD);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fact text too long")]
+    fn test_submit_too_long_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Submit a fact one byte over the limit - should panic
+        let text = String::from_bytes(&env, &[b'a'; MAX_TEXT_LEN as usize + 1]);
+        client.submit_fact(&creator, &text, &CATEGORY, &VOTING_PERIOD);
+    }
+
+    #[test]
+    fn test_submit_fact_at_length_bounds() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Exactly the minimum and maximum lengths are accepted
+        let shortest = String::from_bytes(&env, &[b'a'; MIN_TEXT_LEN as usize]);
+        let longest = String::from_bytes(&env, &[b'a'; MAX_TEXT_LEN as usize]);
+        let short_id = client.submit_fact(&creator, &shortest, &CATEGORY, &VOTING_PERIOD);
+        let long_id = client.submit_fact(&creator, &longest, &CATEGORY, &VOTING_PERIOD);
+
+        assert_eq!(client.get_fact(&short_id).text, shortest);
+        assert_eq!(client.get_fact(&long_id).text, longest);
+    }
+
+    #[test]
+    fn test_submit_fact_records_timestamp() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+
+        // Advance the ledger clock before the second submission
+        env.ledger().set_timestamp(5_000);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
+
+        assert_eq!(client.get_fact(&first_id).created_at, 1_000);
+        assert_eq!(client.get_fact(&second_id).created_at, 5_000);
+    }
+
+    #[test]
+    fn test_submit_fact_emits_event() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "The Sun is a star");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Check the submit event topics and data
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("submit"), creator.clone()).into_val(&env),
+                    (fact_id, fact_text.clone()).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vote_emits_event() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Mount Everest is the tallest mountain");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &fact_id, &false);
+
+        // Check the vote event topics and data
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("vote"), voter.clone()).into_val(&env),
+                    (fact_id, false, 1_u32).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vote_true() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Water freezes at 0°C");
+
+        env.mock_all_auths();
+
+        // Submit a fact
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Vote true
+        client.vote(&voter, &fact_id, &true);
+
+        // Check the vote was recorded
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.voters.len(), 1);
+        assert_eq!(fact.voters.get(0).unwrap(), voter);
+    }
+
+    #[test]
+    fn test_vote_false() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "The Moon is made of cheese");
+
+        env.mock_all_auths();
+
+        // Submit a fact
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Vote false
+        client.vote(&voter, &fact_id, &false);
+
+        // Check the vote was recorded
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 0);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.voters.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already voted on this fact")]
+    fn test_prevent_double_voting() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Rust is awesome");
+
+        env.mock_all_auths();
+
+        // Submit a fact
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // First vote
+        client.vote(&voter, &fact_id, &true);
+
+        // Second vote from same address - should panic
+        client.vote(&voter, &fact_id, &false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Creator cannot vote on own fact")]
+    fn test_creator_cannot_vote_on_own_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "My fact is definitely true");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Creator voting on their own fact - should panic
+        client.vote(&creator, &fact_id, &true);
+    }
+
+    #[test]
+    fn test_vote_before_deadline() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Deadline fact"), &CATEGORY, &100);
+        assert_eq!(client.get_fact(&fact_id).voting_ends_at, 1_100);
+
+        // Voting exactly at the deadline is still allowed
+        env.ledger().set_timestamp(1_100);
+        client.vote(&voter, &fact_id, &true);
+
+        assert_eq!(client.get_fact(&fact_id).true_votes, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Voting period has ended")]
+    fn test_vote_after_deadline() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Deadline fact"), &CATEGORY, &100);
+
+        // Voting after the deadline - should panic
+        env.ledger().set_timestamp(1_101);
+        client.vote(&voter, &fact_id, &true);
+    }
+
+    #[test]
+    fn test_retract_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Light travels faster than sound");
+
+        env.mock_all_auths();
+
+        // Submit a fact and cast two opposing votes
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &fact_id, &true);
+        client.vote(&voter2, &fact_id, &false);
+
+        // Retract the "true" vote
+        client.retract_vote(&voter1, &fact_id);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 0);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.voters.len(), 1);
+        assert_eq!(fact.voters.get(0).unwrap(), voter2);
+
+        // The voter can vote again after retracting
+        client.vote(&voter1, &fact_id, &false);
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 0);
+        assert_eq!(fact.false_votes, 2);
+        assert_eq!(fact.voters.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not voted on this fact")]
+    fn test_retract_vote_without_voting() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Rust has no garbage collector");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Retracting without having voted - should panic
+        client.retract_vote(&voter, &fact_id);
+    }
+
+    #[test]
+    fn test_change_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Bananas are berries");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &fact_id, &false);
+
+        // Flip the vote from false to true
+        client.change_vote(&voter, &fact_id, &true);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.voters.len(), 1);
+
+        // Changing to the same direction is a no-op
+        client.change_vote(&voter, &fact_id, &true);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.voters.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not voted on this fact")]
+    fn test_change_vote_without_voting() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Sound travels in a vacuum");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Changing a vote that was never cast - should panic
+        client.change_vote(&voter, &fact_id, &true);
+    }
+
+    #[test]
+    fn test_anonymous_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_anonymous_fact(&creator, &String::from_str(&env, "Sensitive fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &fact_id, &true);
+        client.vote(&voter2, &fact_id, &false);
+
+        // Tallies grow without exposing who voted
+        let fact = client.get_fact(&fact_id);
+        assert!(fact.anonymous);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.voters.len(), 0);
+        assert_eq!(client.get_voter_history(&voter1).len(), 0);
+        assert_eq!(client.get_total_votes(), 2);
+
+        // The nullifier still blocks a second vote
+        assert!(client.try_vote(&voter1, &fact_id, &false).is_err());
+        assert_eq!(client.get_fact(&fact_id).false_votes, 1);
+
+        // Vote paths that need the voter's address are rejected
+        assert!(client.try_vote_abstain(&Address::generate(&env), &fact_id).is_err());
+        let delegator = Address::generate(&env);
+        let representative = Address::generate(&env);
+        client.delegate(&delegator, &representative);
+        assert!(client.try_vote_for(&representative, &delegator, &fact_id, &true).is_err());
+    }
+
+    #[test]
+    fn test_vote_abstain() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let abstainer = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_min_quorum(&5);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Mixed votes fact"), &CATEGORY, &VOTING_PERIOD);
+        let short_id = client.submit_fact(&creator, &String::from_str(&env, "Fact short of quorum"), &CATEGORY, &VOTING_PERIOD);
+        for is_true in [true, true, true, false] {
+            client.vote(&Address::generate(&env), &fact_id, &is_true);
+            client.vote(&Address::generate(&env), &short_id, &is_true);
+        }
+
+        client.vote_abstain(&abstainer, &fact_id);
+
+        // Abstentions have their own counter and leave the tally alone
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 3);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.abstain_votes, 1);
+        assert_eq!(fact.voters.len(), 5);
+
+        // The abstainer can't vote or abstain again
+        assert!(client.has_voted(&fact_id, &abstainer));
+        assert!(client.try_vote(&abstainer, &fact_id, &true).is_err());
+        assert!(client.try_vote_abstain(&abstainer, &fact_id).is_err());
+
+        // Four votes fall short of the quorum, the abstention completes it
+        end_voting(&env);
+        assert!(client.try_finalize_fact(&short_id).is_err());
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Verified);
+    }
+
+    #[test]
+    fn test_delegated_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let member1 = Address::generate(&env);
+        let member2 = Address::generate(&env);
+        let representative = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.delegate(&member1, &representative);
+        client.delegate(&member2, &representative);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Delegated fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // The representative casts one vote per member
+        client.vote_for(&representative, &member1, &fact_id, &true);
+        client.vote_for(&representative, &member2, &fact_id, &false);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.voters, vec![&env, member1.clone(), member2.clone()]);
+
+        // The member can't vote again directly
+        assert!(client.try_vote(&member1, &fact_id, &false).is_err());
+
+        // Undelegated addresses can't be voted for
+        assert!(client.try_vote_for(&representative, &Address::generate(&env), &fact_id, &true).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Already voted on this fact")]
+    fn test_delegated_vote_after_direct_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let member = Address::generate(&env);
+        let representative = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.delegate(&member, &representative);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Delegated fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // The member votes directly first
+        client.vote(&member, &fact_id, &true);
+
+        // Delegate voting for the same member - should panic
+        client.vote_for(&representative, &member, &fact_id, &false);
+    }
+
+    #[test]
+    fn test_submit_votes_signed() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let unregistered = Address::generate(&env);
+        let key1 = SigningKey::from_bytes(&[1; 32]);
+        let key2 = SigningKey::from_bytes(&[2; 32]);
+        env.mock_all_auths();
+
+        register_key(&env, &client, &voter1, &key1);
+        register_key(&env, &client, &voter2, &key2);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Signed fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // A duplicate and an entry without a registered key are skipped
+        let votes = vec![
+            &env,
+            (voter1.clone(), true, sign_vote(&env, &key1, &contract_id, fact_id, true, 0)),
+            (voter2.clone(), false, sign_vote(&env, &key2, &contract_id, fact_id, false, 0)),
+            (voter1.clone(), true, sign_vote(&env, &key1, &contract_id, fact_id, true, 0)),
+            (unregistered.clone(), true, sign_vote(&env, &key1, &contract_id, fact_id, true, 0)),
+        ];
+        assert_eq!(client.submit_votes_signed(&relayer, &fact_id, &votes), 2);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 1);
+        assert!(client.has_voted(&fact_id, &voter1));
+        assert!(!client.has_voted(&fact_id, &unregistered));
+        assert_eq!(client.get_vote_nonce(&voter1), 1);
+        assert_eq!(client.get_vote_nonce(&unregistered), 0);
+
+        // Resubmitting the same batch applies nothing
+        assert_eq!(client.submit_votes_signed(&relayer, &fact_id, &votes), 0);
+
+        // Replaying a used signature once the vote is retracted is rejected
+        client.retract_vote(&voter1, &fact_id);
+        assert!(client.try_submit_votes_signed(&relayer, &fact_id, &votes).is_err());
+        assert!(!client.has_voted(&fact_id, &voter1));
+
+        // A fresh signature over the next nonce is accepted
+        let revote = vec![&env, (voter1.clone(), false, sign_vote(&env, &key1, &contract_id, fact_id, false, 1))];
+        assert_eq!(client.submit_votes_signed(&relayer, &fact_id, &revote), 1);
+        assert_eq!(client.get_fact(&fact_id).false_votes, 2);
+    }
+
+    #[test]
+    fn test_submit_votes_signed_invalid_signature() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+        let other_id = env.register(FactVerificationContract, ());
+
+        let creator = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let voter3 = Address::generate(&env);
+        let key1 = SigningKey::from_bytes(&[1; 32]);
+        let key2 = SigningKey::from_bytes(&[2; 32]);
+        let key3 = SigningKey::from_bytes(&[3; 32]);
+        env.mock_all_auths();
+
+        register_key(&env, &client, &voter1, &key1);
+        register_key(&env, &client, &voter2, &key2);
+        register_key(&env, &client, &voter3, &key3);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Signed fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // A signature for the opposite choice, or one made for another
+        // deployment, fails the batch it's in
+        let valid = (voter1.clone(), true, sign_vote(&env, &key1, &contract_id, fact_id, true, 0));
+        let flipped = (voter2.clone(), true, sign_vote(&env, &key2, &contract_id, fact_id, false, 0));
+        let foreign = (voter3.clone(), true, sign_vote(&env, &key3, &other_id, fact_id, true, 0));
+        assert!(client.try_submit_votes_signed(&relayer, &fact_id, &vec![&env, valid.clone(), flipped]).is_err());
+        assert!(client.try_submit_votes_signed(&relayer, &fact_id, &vec![&env, valid.clone(), foreign]).is_err());
+        assert_eq!(client.get_fact(&fact_id).true_votes, 0);
+        assert_eq!(client.get_vote_nonce(&voter1), 0);
+
+        // The valid entry goes through on its own
+        assert_eq!(client.submit_votes_signed(&relayer, &fact_id, &vec![&env, valid]), 1);
+        assert!(client.has_voted(&fact_id, &voter1));
+        assert!(!client.has_voted(&fact_id, &voter2));
+        assert!(!client.has_voted(&fact_id, &voter3));
+    }
+
+    #[test]
+    fn test_submit_votes_signed_settles_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let key1 = SigningKey::from_bytes(&[1; 32]);
+        let key2 = SigningKey::from_bytes(&[2; 32]);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_auto_verify_threshold(&1);
+        register_key(&env, &client, &voter1, &key1);
+        register_key(&env, &client, &voter2, &key2);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Signed fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // The first vote verifies the fact, so the rest of the batch is skipped
+        let votes = vec![
+            &env,
+            (voter1.clone(), true, sign_vote(&env, &key1, &contract_id, fact_id, true, 0)),
+            (voter2.clone(), true, sign_vote(&env, &key2, &contract_id, fact_id, true, 0)),
+        ];
+        assert_eq!(client.submit_votes_signed(&relayer, &fact_id, &votes), 1);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Verified);
+        assert!(!client.has_voted(&fact_id, &voter2));
+        assert_eq!(client.get_vote_nonce(&voter2), 0);
+
+        // Batches arriving after voting closes apply nothing
+        let late_id = client.submit_fact(&creator, &String::from_str(&env, "Late signed fact"), &CATEGORY, &VOTING_PERIOD);
+        end_voting(&env);
+        let late = vec![&env, (voter2.clone(), true, sign_vote(&env, &key2, &contract_id, late_id, true, 0))];
+        assert_eq!(client.submit_votes_signed(&relayer, &late_id, &late), 0);
+    }
+
+    #[test]
+    fn test_submit_votes_signed_costs() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let key = SigningKey::from_bytes(&[1; 32]);
+        env.mock_all_auths();
+
+        let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let token_client = token::Client::new(&env, &token_address);
+        token::StellarAssetClient::new(&env, &token_address).mint(&relayer, &100);
+
+        client.initialize(&admin);
+        client.set_token(&token_address);
+        client.set_vote_fee(&3);
+        client.set_vote_deposit(&10);
+        client.set_vote_cooldown(&60);
+        register_key(&env, &client, &voter, &key);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "First signed fact"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Second signed fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // The relayer pays the fee and puts up the deposit
+        let votes = vec![&env, (voter.clone(), true, sign_vote(&env, &key, &contract_id, first_id, true, 0))];
+        assert_eq!(client.submit_votes_signed(&relayer, &first_id, &votes), 1);
+        assert_eq!(token_client.balance(&relayer), 87);
+        assert_eq!(client.get_treasury_balance(), 3);
+
+        // The voter's cooldown applies to signed votes too
+        let votes = vec![&env, (voter.clone(), true, sign_vote(&env, &key, &contract_id, second_id, true, 1))];
+        assert_eq!(client.submit_votes_signed(&relayer, &second_id, &votes), 0);
+        env.ledger().set_timestamp(60);
+        assert_eq!(client.submit_votes_signed(&relayer, &second_id, &votes), 1);
+
+        // The deposits go back to the relayer when the facts settle
+        end_voting(&env);
+        client.finalize_fact(&first_id);
+        client.finalize_fact(&second_id);
+        assert_eq!(token_client.balance(&relayer), 94);
+        assert_eq!(token_client.balance(&voter), 0);
+    }
+
+    #[test]
+    fn test_vote_cooldown() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_vote_cooldown(&30);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "First throttled fact"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Second throttled fact"), &CATEGORY, &VOTING_PERIOD);
+
+        env.ledger().set_timestamp(1_000);
+        client.vote(&voter, &first_id, &true);
+
+        // Once the cooldown has passed the voter can vote again
+        env.ledger().set_timestamp(1_030);
+        client.vote(&voter, &second_id, &false);
+
+        // Other voters aren't affected by someone else's cooldown
+        client.vote(&Address::generate(&env), &first_id, &true);
+        assert_eq!(client.get_fact(&first_id).true_votes, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Voting too frequently")]
+    fn test_vote_within_cooldown() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_vote_cooldown(&30);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "First throttled fact"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Second throttled fact"), &CATEGORY, &VOTING_PERIOD);
+
+        env.ledger().set_timestamp(1_000);
+        client.vote(&voter, &first_id, &true);
+
+        // Voting again on another fact within the cooldown - should panic
+        env.ledger().set_timestamp(1_029);
+        client.vote(&voter, &second_id, &true);
+    }
+
+    #[test]
+    fn test_vote_cooldown_other_paths() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let representative = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_vote_cooldown(&30);
+        let sealed_id = client.submit_sealed_fact(&creator, &String::from_str(&env, "Sealed throttled fact"), &CATEGORY, &VOTING_PERIOD, &REVEAL_PERIOD);
+        let salt = BytesN::from_array(&env, &[1; 32]);
+        client.commit_vote(&voter, &sealed_id, &commitment(&env, true, &salt));
+
+        end_voting(&env);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "First throttled fact"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Second throttled fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &first_id, &true);
+
+        // Revealing is a vote, so it waits out the cooldown
+        assert!(client.try_reveal_vote(&voter, &sealed_id, &true, &salt).is_err());
+        env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+        client.reveal_vote(&voter, &sealed_id, &true, &salt);
+
+        // So does a vote a delegate casts for the voter
+        client.delegate(&voter, &representative);
+        assert!(client.try_vote_for(&representative, &voter, &second_id, &false).is_err());
+        env.ledger().set_timestamp(env.ledger().timestamp() + 30);
+        client.vote_for(&representative, &voter, &second_id, &false);
+
+        assert_eq!(client.get_fact(&sealed_id).true_votes, 1);
+        assert_eq!(client.get_fact(&second_id).false_votes, 1);
+    }
+
+    #[test]
+    fn test_submit_cooldown() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_submit_cooldown(&60);
+
+        env.ledger().set_timestamp(1_000);
+        client.submit_fact(&creator, &String::from_str(&env, "First submission"), &CATEGORY, &VOTING_PERIOD);
+
+        // Once the cooldown has passed the creator can submit again
+        env.ledger().set_timestamp(1_060);
+        client.submit_fact(&creator, &String::from_str(&env, "Second submission"), &CATEGORY, &VOTING_PERIOD);
+
+        // Other creators aren't affected by someone else's cooldown
+        client.submit_fact(&Address::generate(&env), &String::from_str(&env, "Another creator"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_fact_count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Submitting too frequently")]
+    fn test_submit_within_cooldown() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_submit_cooldown(&60);
+
+        env.ledger().set_timestamp(1_000);
+        client.submit_fact(&creator, &String::from_str(&env, "First submission"), &CATEGORY, &VOTING_PERIOD);
+
+        // Submitting again within the cooldown - should panic
+        env.ledger().set_timestamp(1_059);
+        client.submit_fact(&creator, &String::from_str(&env, "Too soon submission"), &CATEGORY, &VOTING_PERIOD);
+    }
+
+    #[test]
+    fn test_submission_gate() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let holder = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Set up a gating token where only one account meets the threshold
+        let gate_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_admin = token::StellarAssetClient::new(&env, &gate_token.address());
+        token_admin.mint(&holder, &100);
+        token_admin.mint(&outsider, &99);
+
+        client.initialize(&admin);
+        client.set_submission_gate(&gate_token.address(), &100);
+
+        // The holder at the threshold can submit
+        let fact_id = client.submit_fact(&holder, &String::from_str(&env, "Holder's fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // The account below it can't
+        let result = client.try_submit_fact(&outsider, &String::from_str(&env, "Outsider's fact"), &CATEGORY, &VOTING_PERIOD);
+        assert!(result.is_err());
+        assert_eq!(client.get_fact_count(), 1);
+
+        // Voting stays open to everyone
+        client.vote(&outsider, &fact_id, &true);
+        assert_eq!(client.get_fact(&fact_id).true_votes, 1);
+    }
+
+    #[test]
+    fn test_vote_with_stake() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let voter3 = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Set up a stake token and fund the voters
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &stake_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &stake_token.address());
+        token_admin.mint(&voter1, &1_000);
+        token_admin.mint(&voter2, &1_000);
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Staked fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Weighted and plain votes accumulate together
+        client.vote_with_stake(&voter1, &fact_id, &true, &300);
+        client.vote_with_stake(&voter2, &fact_id, &false, &100);
+        client.vote(&voter3, &fact_id, &true);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 301);
+        assert_eq!(fact.false_votes, 100);
+        assert_eq!(fact.voters.len(), 3);
+
+        // Stakes moved into the contract
+        assert_eq!(token_client.balance(&voter1), 700);
+        assert_eq!(token_client.balance(&voter2), 900);
+        assert_eq!(token_client.balance(&contract_id), 400);
+
+        // Flipping a staked vote moves its full weight
+        client.change_vote(&voter2, &fact_id, &true);
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 401);
+        assert_eq!(fact.false_votes, 0);
+
+        // Retracting a staked vote removes its weight and refunds the stake
+        client.retract_vote(&voter1, &fact_id);
+        assert_eq!(client.get_fact(&fact_id).true_votes, 101);
+        assert_eq!(token_client.balance(&voter1), 1_000);
+        assert_eq!(token_client.balance(&contract_id), 100);
+    }
+
+    #[test]
+    fn test_vote_with_large_stakes() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let believer = Address::generate(&env);
+        let skeptic = Address::generate(&env);
+        env.mock_all_auths();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &stake_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &stake_token.address());
+        token_admin.mint(&believer, &(u32::MAX as i128));
+        token_admin.mint(&skeptic, &(u32::MAX as i128));
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Heavily staked fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Opposing stakes whose sum doesn't fit in a u32
+        client.vote_with_stake(&believer, &fact_id, &true, &(u32::MAX as i128));
+        client.vote_with_stake(&skeptic, &fact_id, &false, &3_000_000_000);
+        let total = u32::MAX as u64 + 3_000_000_000;
+        assert_eq!(client.get_consensus(&fact_id), ((u32::MAX as u64 * 100 / total) as u32, total));
+        assert!(client.get_controversy(&fact_id) > u32::MAX as u64);
+        assert_eq!(client.get_least_voted_facts(&1).get(0).unwrap().id, fact_id);
+
+        // The fact still settles and both stakes can be claimed back
+        end_voting(&env);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Verified);
+        client.claim_stake(&believer, &fact_id);
+        client.claim_stake(&skeptic, &fact_id);
+        assert_eq!(token_client.balance(&believer), u32::MAX as i128);
+        assert_eq!(token_client.balance(&skeptic), u32::MAX as i128);
+        assert_eq!(client.get_total_votes(), total);
+    }
+
+    #[test]
+    fn test_vote_fee() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Set up a fee token
+        let fee_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &fee_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &fee_token.address());
+
+        client.initialize(&admin);
+        client.set_token(&fee_token.address());
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Fact with a voting fee"), &CATEGORY, &VOTING_PERIOD);
+
+        // Voting is free until a fee is set
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        assert_eq!(client.get_treasury_balance(), 0);
+
+        client.set_vote_fee(&3);
+        for n in 1..=3 {
+            let voter = Address::generate(&env);
+            token_admin.mint(&voter, &10);
+            client.vote(&voter, &fact_id, &true);
+
+            // The treasury grows by the fee with each vote
+            assert_eq!(token_client.balance(&voter), 7);
+            assert_eq!(client.get_treasury_balance(), 3 * n);
+        }
+        assert_eq!(token_client.balance(&contract_id), 9);
+
+        // Votes with a reason pay the fee too
+        let reasoner = Address::generate(&env);
+        token_admin.mint(&reasoner, &10);
+        client.vote_with_reason(&reasoner, &fact_id, &false, &symbol_short!("outdated"));
+        assert_eq!(token_client.balance(&reasoner), 7);
+        assert_eq!(client.get_treasury_balance(), 12);
+
+        // A delegate pays for the votes they cast on someone else's behalf
+        let delegator = Address::generate(&env);
+        let representative = Address::generate(&env);
+        token_admin.mint(&representative, &10);
+        client.delegate(&delegator, &representative);
+        client.vote_for(&representative, &delegator, &fact_id, &false);
+        assert_eq!(token_client.balance(&representative), 7);
+        assert_eq!(token_client.balance(&delegator), 0);
+        assert_eq!(client.get_treasury_balance(), 15);
+        assert_eq!(token_client.balance(&contract_id), 15);
+    }
+
+    #[test]
+    fn test_withdraw_treasury() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fee_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let other_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &fee_token.address());
+        token::StellarAssetClient::new(&env, &fee_token.address()).mint(&staker, &1_000);
+
+        client.initialize(&admin);
+        client.set_token(&fee_token.address());
+        client.set_vote_fee(&5);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Fact with a voting fee"), &CATEGORY, &VOTING_PERIOD);
+        client.vote_with_stake(&staker, &fact_id, &true, &100);
+        assert_eq!(client.get_treasury_balance(), 5);
+
+        // The held stake can't be paid out as treasury
+        assert!(client.try_withdraw_treasury(&recipient, &6).is_err());
+        assert!(client.try_withdraw_treasury(&recipient, &0).is_err());
+
+        client.withdraw_treasury(&recipient, &3);
+        let events = env.events().all();
+        assert_eq!(
+            events.slice(events.len() - 1..),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("withdraw")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(token_client.balance(&recipient), 3);
+        assert_eq!(client.get_treasury_balance(), 2);
+
+        // The token can't change until the treasury is emptied
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        client.claim_stake(&staker, &fact_id);
+        assert!(client.try_set_token(&other_token.address()).is_err());
+        client.withdraw_treasury(&recipient, &2);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        client.set_token(&other_token.address());
+    }
+
+    #[test]
+    fn test_vote_deposit() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let flip_flopper = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Set up a deposit token and fund the voters
+        let deposit_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &deposit_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &deposit_token.address());
+        for voter in [&voter1, &voter2, &flip_flopper] {
+            token_admin.mint(voter, &100);
+        }
+
+        client.initialize(&admin);
+        client.set_token(&deposit_token.address());
+        client.set_vote_deposit(&10);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Deposit fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &fact_id, &true);
+        client.vote(&voter2, &fact_id, &true);
+        client.vote(&flip_flopper, &fact_id, &false);
+        assert_eq!(token_client.balance(&voter1), 90);
+        assert_eq!(token_client.balance(&contract_id), 30);
+
+        // Retracting forfeits the deposit to the treasury
+        client.retract_vote(&flip_flopper, &fact_id);
+        assert_eq!(token_client.balance(&flip_flopper), 90);
+        assert_eq!(client.get_treasury_balance(), 10);
+
+        // Votes that stood are refunded at finalization
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        assert_eq!(token_client.balance(&voter1), 100);
+        assert_eq!(token_client.balance(&voter2), 100);
+        assert_eq!(token_client.balance(&flip_flopper), 90);
+        assert_eq!(token_client.balance(&contract_id), 10);
+
+        // Facts that will never settle refund their deposits too
+        let deleted_id = client.submit_fact(&creator, &String::from_str(&env, "Deleted deposit fact"), &CATEGORY, &VOTING_PERIOD);
+        let expiring_id = client.submit_fact(&creator, &String::from_str(&env, "Expiring deposit fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &deleted_id, &true);
+        client.vote(&voter2, &expiring_id, &true);
+        assert_eq!(token_client.balance(&contract_id), 30);
+
+        client.delete_fact(&deleted_id);
+        assert_eq!(token_client.balance(&voter1), 100);
+
+        let now = env.ledger().timestamp();
+        client.set_fact_expiry(&expiring_id, &(now + 100));
+        env.ledger().set_timestamp(now + 101);
+        client.sweep_expired(&Vec::from_array(&env, [expiring_id]));
+        assert_eq!(token_client.balance(&voter2), 100);
+        assert_eq!(token_client.balance(&contract_id), 10);
+    }
+
+    #[test]
+    fn test_claim_stake() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        env.mock_all_auths();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &stake_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &stake_token.address());
+        token_admin.mint(&winner, &1_000);
+        token_admin.mint(&loser, &1_000);
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Staked fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote_with_stake(&winner, &fact_id, &true, &300);
+        client.vote_with_stake(&loser, &fact_id, &false, &100);
+
+        // Stakes stay locked while the fact is open
+        assert!(client.try_claim_stake(&winner, &fact_id).is_err());
+
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        client.claim_stake(&winner, &fact_id);
+        client.claim_stake(&loser, &fact_id);
+
+        // Everyone gets their stake back and the contract holds nothing
+        assert_eq!(token_client.balance(&winner), 1_000);
+        assert_eq!(token_client.balance(&loser), 1_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+
+        // The settled tally is unchanged
+        assert_eq!(client.get_fact(&fact_id).true_votes, 300);
+    }
+
+    #[test]
+    #[should_panic(expected = "No stake to claim")]
+    fn test_claim_stake_twice() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&env, &stake_token.address()).mint(&voter, &1_000);
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Staked fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote_with_stake(&voter, &fact_id, &true, &300);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        client.claim_stake(&voter, &fact_id);
+
+        // Claiming the same stake again - should panic
+        client.claim_stake(&voter, &fact_id);
+    }
+
+    #[test]
+    fn test_set_token_while_stakes_held() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let other_token = env.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&env, &stake_token.address()).mint(&voter, &1_000);
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+        client.set_vote_deposit(&50);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Staked fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote_with_stake(&voter, &fact_id, &true, &300);
+
+        // Refunds would be paid in the wrong token
+        assert!(client.try_set_token(&other_token.address()).is_err());
+
+        // Settling refunds the deposit, but the stake is still unclaimed
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        assert!(client.try_set_token(&other_token.address()).is_err());
+
+        // Once everything is returned the token can change
+        client.claim_stake(&voter, &fact_id);
+        client.set_token(&other_token.address());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid stake")]
+    fn test_vote_with_zero_stake() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Staked fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Zero stake - should panic
+        client.vote_with_stake(&Address::generate(&env), &fact_id, &true, &0);
+    }
+
+    fn commitment(env: &Env, is_true: bool, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage = Bytes::new(env);
+        preimage.push_back(is_true as u8);
+        preimage.append(&salt.clone().into());
+        env.crypto().sha256(&preimage).to_bytes()
+    }
+
+    #[test]
+    fn test_commit_reveal_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_sealed_fact(&creator, &String::from_str(&env, "Sealed fact"), &CATEGORY, &VOTING_PERIOD, &REVEAL_PERIOD);
+        assert!(client.get_fact(&fact_id).sealed);
+
+        let salt1 = BytesN::from_array(&env, &[1; 32]);
+        let salt2 = BytesN::from_array(&env, &[2; 32]);
+        client.commit_vote(&voter1, &fact_id, &commitment(&env, true, &salt1));
+        client.commit_vote(&voter2, &fact_id, &commitment(&env, false, &salt2));
+
+        // Commitments don't show up in the tally
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 0);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.reveal_ends_at, VOTING_PERIOD + REVEAL_PERIOD);
+
+        // Revealing isn't possible while commitments are still open
+        assert!(client.try_reveal_vote(&voter1, &fact_id, &true, &salt1).is_err());
+
+        // Revealing within the reveal window applies the committed votes
+        end_voting(&env);
+        client.reveal_vote(&voter1, &fact_id, &true, &salt1);
+        client.reveal_vote(&voter2, &fact_id, &false, &salt2);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.voters.len(), 2);
+
+        // Plain votes are rejected on sealed facts
+        assert!(client.try_vote(&Address::generate(&env), &fact_id, &true).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Voting period has ended")]
+    fn test_reveal_after_window() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_sealed_fact(&creator, &String::from_str(&env, "Sealed fact"), &CATEGORY, &VOTING_PERIOD, &REVEAL_PERIOD);
+
+        let salt = BytesN::from_array(&env, &[5; 32]);
+        client.commit_vote(&voter, &fact_id, &commitment(&env, true, &salt));
+
+        // Revealing after the reveal window has closed - should panic
+        env.ledger().set_timestamp(VOTING_PERIOD + REVEAL_PERIOD + 1);
+        client.reveal_vote(&voter, &fact_id, &true, &salt);
+    }
+
+    #[test]
+    fn test_unrevealed_vote_not_counted() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let revealer = Address::generate(&env);
+        let silent = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_sealed_fact(&creator, &String::from_str(&env, "Sealed fact"), &CATEGORY, &VOTING_PERIOD, &REVEAL_PERIOD);
+
+        let salt = BytesN::from_array(&env, &[6; 32]);
+        client.commit_vote(&revealer, &fact_id, &commitment(&env, true, &salt));
+        client.commit_vote(&silent, &fact_id, &commitment(&env, false, &salt));
+
+        // Only one voter reveals
+        end_voting(&env);
+        client.reveal_vote(&revealer, &fact_id, &true, &salt);
+
+        // The tally can't be settled until the reveal window closes
+        assert!(client.try_finalize_fact(&fact_id).is_err());
+
+        // The unrevealed commitment never reaches the tally
+        env.ledger().set_timestamp(VOTING_PERIOD + REVEAL_PERIOD + 1);
+        client.finalize_fact(&fact_id);
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.voters, vec![&env, revealer.clone()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Reveal does not match commitment")]
+    fn test_reveal_with_wrong_salt() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_sealed_fact(&creator, &String::from_str(&env, "Sealed fact"), &CATEGORY, &VOTING_PERIOD, &REVEAL_PERIOD);
+
+        let salt = BytesN::from_array(&env, &[7; 32]);
+        client.commit_vote(&voter, &fact_id, &commitment(&env, true, &salt));
+
+        // Revealing with a different salt - should panic
+        client.reveal_vote(&voter, &fact_id, &true, &BytesN::from_array(&env, &[8; 32]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Already committed to this fact")]
+    fn test_prevent_double_commit() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_sealed_fact(&creator, &String::from_str(&env, "Sealed fact"), &CATEGORY, &VOTING_PERIOD, &REVEAL_PERIOD);
+
+        let salt = BytesN::from_array(&env, &[3; 32]);
+        client.commit_vote(&voter, &fact_id, &commitment(&env, true, &salt));
+
+        // Second commitment from same address - should panic
+        client.commit_vote(&voter, &fact_id, &commitment(&env, false, &salt));
+    }
+
+    #[test]
+    fn test_multiple_voters() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let voter3 = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Blockchain is decentralized");
+
+        env.mock_all_auths();
+
+        // Submit a fact
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+
+        // Multiple voters
+        client.vote(&voter1, &fact_id, &true);
+        client.vote(&voter2, &fact_id, &true);
+        client.vote(&voter3, &fact_id, &false);
+
+        // Check votes
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 2);
+        assert_eq!(fact.false_votes, 1);
+        assert_eq!(fact.voters.len(), 3);
+    }
+
+    #[test]
+    fn test_finalize_verified() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Honey never spoils");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        end_voting(&env);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Verified);
+
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Verified);
+    }
+
+    #[test]
+    #[should_panic(expected = "Voting period still open")]
+    fn test_finalize_before_deadline() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Settled too early"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+
+        // Finalizing while votes are still accepted - should panic
+        env.ledger().set_timestamp(VOTING_PERIOD);
+        client.finalize_fact(&fact_id);
+    }
+
+    #[test]
+    fn test_finalize_debunked() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Goldfish have a three second memory");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        end_voting(&env);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Debunked);
+
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Debunked);
+    }
+
+    #[test]
+    fn test_finalize_disputed() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Coffee stunts your growth");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        end_voting(&env);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Disputed);
+
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Disputed);
+    }
+
+    #[test]
+    fn test_balance_weighted_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Set up a voting token and fund the voter
+        let vote_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_admin = token::StellarAssetClient::new(&env, &vote_token.address());
+        token_admin.mint(&voter, &100);
+
+        client.initialize(&admin);
+        client.set_token(&vote_token.address());
+        client.set_balance_weighting(&true);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Balance weighted fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &fact_id, &true);
+        assert_eq!(client.get_vote_weight(&fact_id, &voter), 100);
+
+        // Tokens acquired after the first vote don't add voting power
+        token_admin.mint(&voter, &900);
+        client.retract_vote(&voter, &fact_id);
+        client.vote(&voter, &fact_id, &false);
+        assert_eq!(client.get_vote_weight(&fact_id, &voter), 100);
+        assert_eq!(client.get_fact(&fact_id).false_votes, 100);
+
+        // Addresses without tokens can't vote
+        let result = client.try_vote(&Address::generate(&env), &fact_id, &true);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Reward token is the stake token")]
+    fn test_creator_reward_in_stake_token() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let token = Address::generate(&env);
+        let reward_token = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_creator_reward(&reward_token, &50);
+
+        // The stake token can't become the reward token either way round
+        assert!(client.try_set_token(&reward_token).is_err());
+        client.set_token(&token);
+        client.set_creator_reward(&token, &50);
+    }
+
+    #[test]
+    fn test_creator_reward() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Set up a reward token and fund the contract's pool
+        let reward_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &reward_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &reward_token.address());
+        token_admin.mint(&contract_id, &1_000);
+
+        client.initialize(&admin);
+        client.set_creator_reward(&reward_token.address(), &50);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Rewarded fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        assert_eq!(token_client.balance(&creator), 50);
+        assert!(client.get_fact(&fact_id).rewarded);
+
+        // Verifying the fact again after a challenge doesn't pay out twice
+        client.challenge_fact(&Address::generate(&env), &fact_id);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Verified);
+        assert_eq!(token_client.balance(&creator), 50);
+
+        // Debunked facts earn nothing
+        let debunked_id = client.submit_fact(&creator, &String::from_str(&env, "Unrewarded fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &debunked_id, &false);
+        client.vote(&Address::generate(&env), &debunked_id, &false);
+        end_voting(&env);
+        client.finalize_fact(&debunked_id);
+        assert_eq!(token_client.balance(&creator), 50);
+        assert_eq!(token_client.balance(&contract_id), 950);
+    }
+
+    #[test]
+    fn test_finalize_modes() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let mode_fact = |mode: FinalizeMode| {
+            env.mock_all_auths();
+            client.set_finalize_mode(&mode);
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Mode fact", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            end_voting(&env);
+            fact_id
+        };
+
+        // By default anyone may finalize, without any authorization
+        let fact_id = mode_fact(FinalizeMode::Anyone);
+        env.mock_auths(&[]);
+        client.finalize_fact(&fact_id);
+
+        // Only the admin may finalize under AdminOnly
+        let fact_id = mode_fact(FinalizeMode::AdminOnly);
+        let finalize_invoke = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "finalize_fact",
+            args: (fact_id,).into_val(&env),
+            sub_invokes: &[],
+        };
+        env.mock_auths(&[MockAuth { address: &creator, invoke: &finalize_invoke }]);
+        assert!(client.try_finalize_fact(&fact_id).is_err());
+        env.mock_auths(&[MockAuth { address: &admin, invoke: &finalize_invoke }]);
+        client.finalize_fact(&fact_id);
+
+        // Only the fact's creator may finalize under CreatorOnly
+        let fact_id = mode_fact(FinalizeMode::CreatorOnly);
+        let finalize_invoke = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "finalize_fact",
+            args: (fact_id,).into_val(&env),
+            sub_invokes: &[],
+        };
+        env.mock_auths(&[MockAuth { address: &admin, invoke: &finalize_invoke }]);
+        assert!(client.try_finalize_fact(&fact_id).is_err());
+        env.mock_auths(&[MockAuth { address: &creator, invoke: &finalize_invoke }]);
+        client.finalize_fact(&fact_id);
+    }
+
+    #[test]
+    fn test_finalize_with_supermajority() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_supermajority(&75);
+
+        let tally = |true_votes: u32, false_votes: u32| {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Contentious claim", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            for _ in 0..true_votes {
+                client.vote(&Address::generate(&env), &fact_id, &true);
+            }
+            for _ in 0..false_votes {
+                client.vote(&Address::generate(&env), &fact_id, &false);
+            }
+            end_voting(&env);
+            client.finalize_fact(&fact_id)
+        };
+
+        // Exactly 75% wins
+        assert_eq!(tally(3, 1), FactStatus::Verified);
+        assert_eq!(tally(1, 3), FactStatus::Debunked);
+
+        // Just below it is disputed, even with a clear lead
+        assert_eq!(tally(5, 2), FactStatus::Disputed);
+        assert_eq!(tally(2, 5), FactStatus::Disputed);
+
+        // Well above it wins
+        assert_eq!(tally(9, 1), FactStatus::Verified);
+
+        // No votes at all is disputed
+        assert_eq!(tally(0, 0), FactStatus::Disputed);
+    }
+
+    #[test]
+    fn test_category_thresholds() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Breaking news settles on a few votes, science needs a broad consensus
+        let news = symbol_short!("news");
+        let science = symbol_short!("science");
+        client.initialize(&admin);
+        client.set_min_quorum(&4);
+        client.set_category_thresholds(&news, &2, &0);
+        client.set_category_thresholds(&science, &5, &80);
+
+        let tally = |category: &Symbol, true_votes: u32, false_votes: u32| {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Categorized claim", client.get_fact_count()), category, &VOTING_PERIOD);
+            for _ in 0..true_votes {
+                client.vote(&Address::generate(&env), &fact_id, &true);
+            }
+            for _ in 0..false_votes {
+                client.vote(&Address::generate(&env), &fact_id, &false);
+            }
+            end_voting(&env);
+            client.try_finalize_fact(&fact_id).ok().map(|status| status.unwrap())
+        };
+
+        // Two votes are enough for news, below the global quorum
+        assert_eq!(tally(&news, 2, 0), Some(FactStatus::Verified));
+
+        // Science needs five votes and 80% of them
+        assert_eq!(tally(&science, 4, 0), None);
+        assert_eq!(tally(&science, 4, 1), Some(FactStatus::Verified));
+        assert_eq!(tally(&science, 6, 2), Some(FactStatus::Disputed));
+
+        // Other categories use the global settings
+        assert_eq!(tally(&CATEGORY, 3, 0), None);
+        assert_eq!(tally(&CATEGORY, 6, 2), Some(FactStatus::Verified));
+
+        // Cleared categories fall back to them too
+        client.clear_category_thresholds(&news);
+        assert_eq!(tally(&news, 2, 0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid supermajority")]
+    fn test_set_supermajority_at_half() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // Half would let both sides win a tie - should panic
+        client.set_supermajority(&50);
+    }
+
+    #[test]
+    fn test_finalize_ignores_blacklisted_votes() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let sybil1 = Address::generate(&env);
+        let sybil2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // Three honest true votes against two coordinated false votes
+        let brigaded = || {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Brigaded fact", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            for _ in 0..3 {
+                client.vote(&Address::generate(&env), &fact_id, &true);
+            }
+            client.vote(&sybil1, &fact_id, &false);
+            client.vote(&sybil2, &fact_id, &false);
+            end_voting(&env);
+            fact_id
+        };
+
+        // Counted naively the lead is too small for a verdict
+        let naive_id = brigaded();
+        assert_eq!(client.finalize_fact(&naive_id), FactStatus::Disputed);
+
+        // Without the blacklisted votes the fact is verified
+        let adjusted_id = brigaded();
+        client.blacklist(&sybil1);
+        client.blacklist(&sybil2);
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("blacklist")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(client.finalize_fact(&adjusted_id), FactStatus::Verified);
+
+        // The stored counts still show every vote
+        assert_eq!(client.get_fact(&adjusted_id).false_votes, 2);
+
+        // Unblacklisted voters count again
+        let restored_id = brigaded();
+        client.unblacklist(&sybil1);
+        client.unblacklist(&sybil2);
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), Symbol::new(&env, "unblacklist")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(client.finalize_fact(&restored_id), FactStatus::Disputed);
+    }
+
+    #[test]
+    fn test_finalize_with_rep_floor() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let veteran1 = Address::generate(&env);
+        let veteran2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // The veterans earn reputation on an earlier fact
+        let warm_up_id = client.submit_fact(&creator, &String::from_str(&env, "Warm-up fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&veteran1, &warm_up_id, &true);
+        client.vote(&veteran2, &warm_up_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&warm_up_id);
+
+        // Two veterans against a swarm of fresh accounts
+        let swarmed = || {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Swarmed fact", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            client.vote(&veteran1, &fact_id, &true);
+            client.vote(&veteran2, &fact_id, &true);
+            for _ in 0..6 {
+                client.vote(&Address::generate(&env), &fact_id, &false);
+            }
+            end_voting(&env);
+            fact_id
+        };
+
+        // Counted naively the swarm wins
+        let naive_id = swarmed();
+        assert_eq!(client.finalize_fact(&naive_id), FactStatus::Debunked);
+
+        // With a floor only the veterans' votes decide
+        client.set_rep_floor(&1);
+        let floored_id = swarmed();
+        assert_eq!(client.finalize_fact(&floored_id), FactStatus::Verified);
+        assert_eq!(client.get_fact(&floored_id).false_votes, 6);
+    }
+
+    #[test]
+    fn test_tie_policies() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // Finalize a fresh fact with two votes on each side
+        let mut n = 0;
+        let mut tie = |policy: TiePolicy| {
+            client.set_tie_policy(&policy, &0);
+            n += 1;
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Tied fact", n), &CATEGORY, &VOTING_PERIOD);
+            for is_true in [true, true, false, false] {
+                client.vote(&Address::generate(&env), &fact_id, &is_true);
+            }
+            end_voting(&env);
+            client.finalize_fact(&fact_id)
+        };
+
+        assert_eq!(tie(TiePolicy::Disputed), FactStatus::Disputed);
+        assert_eq!(tie(TiePolicy::FavorTrue), FactStatus::Verified);
+        assert_eq!(tie(TiePolicy::FavorFalse), FactStatus::Debunked);
+
+        // A fact without votes isn't a tie
+        let empty_id = client.submit_fact(&creator, &String::from_str(&env, "Unvoted fact"), &CATEGORY, &VOTING_PERIOD);
+        end_voting(&env);
+        assert_eq!(client.finalize_fact(&empty_id), FactStatus::Disputed);
+    }
+
+    #[test]
+    fn test_tie_policy_extends_voting() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_tie_policy(&TiePolicy::ExtendVoting, &3_600);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Fact ending in a tie"), &CATEGORY, &VOTING_PERIOD);
+        for is_true in [true, true, false, false] {
+            client.vote(&Address::generate(&env), &fact_id, &is_true);
+        }
+        let voting_ends_at = client.get_fact(&fact_id).voting_ends_at;
+
+        // The tie keeps the fact open for another hour
+        env.ledger().set_timestamp(voting_ends_at + 1);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Open);
+        assert_eq!(client.get_fact(&fact_id).voting_ends_at, voting_ends_at + 3_601);
+
+        // Bulk finalization extends a tie without reporting it
+        env.ledger().set_timestamp(voting_ends_at + 3_602);
+        assert_eq!(client.finalize_due(&(voting_ends_at + 3_602)).len(), 0);
+        assert_eq!(client.get_fact(&fact_id).voting_ends_at, voting_ends_at + 7_202);
+
+        // Votes cast during the extension break the tie
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        env.ledger().set_timestamp(voting_ends_at + 7_203);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Verified);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid tie extension")]
+    fn test_tie_policy_without_extension() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_tie_policy(&TiePolicy::ExtendVoting, &0);
+    }
+
+    #[test]
+    fn test_finalize_due() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Two short facts fall due, one of them already finalized, and one long fact doesn't
+        let due_id = client.submit_fact(&creator, &String::from_str(&env, "Fact that falls due"), &CATEGORY, &100);
+        let long_id = client.submit_fact(&creator, &String::from_str(&env, "Fact with a long window"), &CATEGORY, &VOTING_PERIOD);
+        let done_id = client.submit_fact(&creator, &String::from_str(&env, "Fact already finalized"), &CATEGORY, &100);
+        client.vote(&voter1, &due_id, &true);
+        client.vote(&voter2, &due_id, &true);
+
+        let due_at = client.get_fact(&due_id).voting_ends_at + 1;
+        env.ledger().set_timestamp(due_at);
+        client.finalize_fact(&done_id);
+        assert_eq!(client.finalize_due(&(due_at - 1)), Vec::<u32>::new(&env));
+
+        // The cutoff can't run ahead of the ledger
+        assert!(client.try_finalize_due(&(due_at + VOTING_PERIOD)).is_err());
+
+        // Only the due, still-open fact is finalized
+        let finalized = client.finalize_due(&due_at);
+        assert_eq!(finalized, vec![&env, due_id]);
+        assert_eq!(client.get_fact(&due_id).status, FactStatus::Verified);
+        assert_eq!(client.get_fact(&long_id).status, FactStatus::Open);
+        assert_eq!(client.get_fact(&done_id).status, FactStatus::Disputed);
+
+        // A second sweep has nothing left to do
+        assert_eq!(client.finalize_due(&due_at).len(), 0);
+    }
+
+    #[test]
+    fn test_finalize_due_admin_only() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_finalize_mode(&FinalizeMode::AdminOnly);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "First due fact"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Second due fact"), &CATEGORY, &VOTING_PERIOD);
+        for fact_id in [first_id, second_id] {
+            client.vote(&Address::generate(&env), &fact_id, &true);
+            client.vote(&Address::generate(&env), &fact_id, &true);
+        }
+        end_voting(&env);
+        let now = env.ledger().timestamp();
+
+        // One admin authorization covers every due fact
+        env.mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "finalize_due",
+                args: (now,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert_eq!(client.finalize_due(&now), vec![&env, first_id, second_id]);
+
+        // Without it nothing is finalized
+        env.mock_all_auths();
+        client.challenge_fact(&Address::generate(&env), &first_id);
+        end_voting(&env);
+        env.mock_auths(&[]);
+        assert!(client.try_finalize_due(&env.ledger().timestamp()).is_err());
+
+        // Creator-only facts are left for their creators
+        env.mock_all_auths();
+        client.set_finalize_mode(&FinalizeMode::CreatorOnly);
+        assert_eq!(client.finalize_due(&env.ledger().timestamp()).len(), 0);
+        assert_eq!(client.get_fact(&first_id).status, FactStatus::Disputed);
+    }
+
+    #[test]
+    fn test_finalize_emits_event() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        end_voting(&env);
+        let status = client.finalize_fact(&fact_id);
+        assert_eq!(status, FactStatus::Debunked);
+
+        // The verdict is published for indexers
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("final"), fact_id).into_val(&env),
+                    FactStatus::Debunked.into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Quorum not reached")]
+    fn test_finalize_without_quorum() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_min_quorum(&5);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Quorum fact"), &CATEGORY, &VOTING_PERIOD);
+        for _ in 0..3 {
+            client.vote(&Address::generate(&env), &fact_id, &true);
+        }
+
+        // Only 3 of 5 required votes - should panic
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+    }
+
+    #[test]
+    fn test_finalize_with_quorum() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_min_quorum(&5);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Quorum fact"), &CATEGORY, &VOTING_PERIOD);
+        let quorate_id = client.submit_fact(&creator, &String::from_str(&env, "Quorate fact"), &CATEGORY, &VOTING_PERIOD);
+        for _ in 0..3 {
+            client.vote(&Address::generate(&env), &fact_id, &true);
+            client.vote(&Address::generate(&env), &quorate_id, &true);
+        }
+        client.vote(&Address::generate(&env), &quorate_id, &true);
+        client.vote(&Address::generate(&env), &quorate_id, &false);
+        end_voting(&env);
+        assert!(client.try_finalize_fact(&fact_id).is_err());
+
+        // Reaching the quorum allows finalization
+        client.finalize_fact(&quorate_id);
+
+        assert_eq!(client.get_fact(&quorate_id).status, FactStatus::Verified);
+    }
+
+    #[test]
+    fn test_auto_verify_threshold() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_auto_verify_threshold(&3);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Low-stakes fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Open);
+
+        // The vote reaching the threshold verifies the fact in the same call
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Verified);
+        assert_eq!(client.get_reputation(&voter), 1);
+
+        // No further votes are accepted
+        assert!(client.try_vote(&Address::generate(&env), &fact_id, &true).is_err());
+    }
+
+    #[test]
+    fn test_reputation_after_finalize() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let voter3 = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Reputation fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &fact_id, &true);
+        client.vote(&voter2, &fact_id, &true);
+        client.vote(&voter3, &fact_id, &false);
+
+        // Nobody has reputation before finalization
+        assert_eq!(client.get_reputation(&voter1), 0);
+
+        // Majority voters gain reputation when the fact is finalized
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Verified);
+        assert_eq!(client.get_reputation(&voter1), 1);
+        assert_eq!(client.get_reputation(&voter2), 1);
+        assert_eq!(client.get_reputation(&voter3), 0);
+    }
+
+    #[test]
+    fn test_reputation_decay() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let veteran = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Earn three points of reputation
+        let mut n = 0;
+        let mut earn = || {
+            n += 1;
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Earned fact", n), &CATEGORY, &VOTING_PERIOD);
+            client.vote(&Address::generate(&env), &fact_id, &true);
+            client.vote(&veteran, &fact_id, &true);
+            end_voting(&env);
+            client.finalize_fact(&fact_id);
+        };
+        for _ in 0..3 {
+            earn();
+        }
+        assert_eq!(client.get_reputation(&veteran), 3);
+
+        // A point goes with each idle decay period
+        let earned_at = env.ledger().timestamp();
+        env.ledger().set_timestamp(earned_at + REPUTATION_DECAY_PERIOD - 1);
+        assert_eq!(client.get_reputation(&veteran), 3);
+        env.ledger().set_timestamp(earned_at + 2 * REPUTATION_DECAY_PERIOD);
+        assert_eq!(client.get_reputation(&veteran), 1);
+
+        // New reputation builds on what's left and restarts the clock
+        earn();
+        assert_eq!(client.get_reputation(&veteran), 2);
+
+        // Far in the future it bottoms out at zero, and so does the vote bonus
+        env.ledger().set_timestamp(earned_at + 100 * REPUTATION_DECAY_PERIOD);
+        assert_eq!(client.get_reputation(&veteran), 0);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Fact after a long break"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&veteran, &fact_id, &true);
+        assert_eq!(client.get_vote_weight(&fact_id, &veteran), 1);
+    }
+
+    #[test]
+    fn test_reputation_weighted_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let veteran = Address::generate(&env);
+        let newcomer = Address::generate(&env);
+        env.mock_all_auths();
+
+        // The veteran builds up reputation past the bonus cap
+        for n in 0..MAX_REPUTATION_BONUS + 2 {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Warm-up fact", n), &CATEGORY, &VOTING_PERIOD);
+            client.vote(&Address::generate(&env), &fact_id, &true);
+            client.vote(&veteran, &fact_id, &true);
+            end_voting(&env);
+            client.finalize_fact(&fact_id);
+        }
+        assert_eq!(client.get_reputation(&veteran), MAX_REPUTATION_BONUS as i32 + 2);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Weighted fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&veteran, &fact_id, &true);
+        client.vote(&newcomer, &fact_id, &false);
+
+        // The veteran's vote counts for more, but only up to the cap
+        assert_eq!(client.get_vote_weight(&fact_id, &veteran), 1 + MAX_REPUTATION_BONUS);
+        assert_eq!(client.get_vote_weight(&fact_id, &newcomer), 1);
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 1 + MAX_REPUTATION_BONUS);
+        assert_eq!(fact.false_votes, 1);
+
+        // Retracting removes exactly the weight that was added
+        client.retract_vote(&veteran, &fact_id);
+        assert_eq!(client.get_fact(&fact_id).true_votes, 0);
+    }
+
+    #[test]
+    fn test_challenge_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let challenger = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Challenged fact"), &CATEGORY, &100);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        env.ledger().set_timestamp(1_101);
+        client.finalize_fact(&fact_id);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Verified);
+
+        // Challenge after the voting window has closed
+        env.ledger().set_timestamp(2_000);
+        client.challenge_fact(&challenger, &fact_id);
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.status, FactStatus::Disputed);
+        assert_eq!(fact.challengers, vec![&env, challenger.clone()]);
+        assert_eq!(fact.voting_ends_at, 2_100);
+
+        // Voting resumes on the challenged fact
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+        assert_eq!(client.get_fact(&fact_id).false_votes, 4);
+
+        // The fact can be settled again once the new window closes
+        env.ledger().set_timestamp(2_101);
+        client.finalize_fact(&fact_id);
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Debunked);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only finalized facts can be challenged")]
+    fn test_challenge_open_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Still open"), &CATEGORY, &VOTING_PERIOD);
+
+        // Challenging a fact that was never finalized - should panic
+        client.challenge_fact(&Address::generate(&env), &fact_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fact has been deleted")]
+    fn test_challenge_deleted_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Verified then deleted"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        client.delete_fact(&fact_id);
+        assert_eq!(client.get_status_counts(), (0, 0, 0, 0, 0));
+
+        // Challenging the tombstone - should panic
+        client.challenge_fact(&Address::generate(&env), &fact_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fact already finalized")]
+    fn test_vote_after_finalize() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let fact_text = String::from_str(&env, "Lightning never strikes twice");
+
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &fact_text, &CATEGORY, &VOTING_PERIOD);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+
+        // Late vote on a settled fact - should panic
+        client.vote(&Address::generate(&env), &fact_id, &true);
+    }
+
+    #[test]
+    fn test_edit_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Teh Earth is round"), &CATEGORY, &VOTING_PERIOD);
+
+        client.edit_fact(&fact_id, &String::from_str(&env, "The Earth is round"));
+
+        assert_eq!(client.get_fact(&fact_id).text, String::from_str(&env, "The Earth is round"));
+    }
+
+    #[test]
+    fn test_fact_history() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let first = String::from_str(&env, "Teh Earth is round");
+        let second = String::from_str(&env, "The Earth is rund");
+        let fact_id = client.submit_fact(&creator, &first, &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_fact_history(&fact_id).len(), 0);
+
+        client.edit_fact(&fact_id, &second);
+        client.edit_fact(&fact_id, &String::from_str(&env, "The Earth is round"));
+
+        // Prior versions are kept oldest first
+        assert_eq!(client.get_fact_history(&fact_id), vec![&env, first, second]);
+    }
+
+    #[test]
+    fn test_fact_history_is_capped() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Revision", 0), &CATEGORY, &VOTING_PERIOD);
+        for n in 1..=MAX_HISTORY + 2 {
+            client.edit_fact(&fact_id, &numbered_text(&env, "Revision", n));
+        }
+
+        // The two oldest versions were dropped
+        let history = client.get_fact_history(&fact_id);
+        assert_eq!(history.len(), MAX_HISTORY);
+        assert_eq!(history.get(0).unwrap(), numbered_text(&env, "Revision", 2));
+        assert_eq!(history.last().unwrap(), numbered_text(&env, "Revision", MAX_HISTORY + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot edit after voting started")]
+    fn test_edit_fact_after_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Original text"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+
+        // Editing once a vote has been cast - should panic
+        client.edit_fact(&fact_id, &String::from_str(&env, "Changed text"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot edit after voting started")]
+    fn test_edit_anonymous_fact_after_vote() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_anonymous_fact(&creator, &String::from_str(&env, "Original text"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+
+        // The anonymous vote leaves no voter behind but still blocks edits
+        client.edit_fact(&fact_id, &String::from_str(&env, "Changed text"));
+    }
+
+    #[test]
+    fn test_delete_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Submitted by mistake"), &CATEGORY, &VOTING_PERIOD);
+
+        client.delete_fact(&second_id);
+
+        // Deleted facts are skipped by get_all_facts
+        let all_facts = client.get_all_facts();
+        assert_eq!(all_facts.len(), 1);
+        assert_eq!(all_facts.get(0).unwrap().id, first_id);
+
+        // The deleted fact is still fetchable, flagged as deleted
+        let deleted = client.get_fact(&second_id);
+        assert!(deleted.deleted);
+        assert_eq!(deleted.text, String::from_str(&env, "Submitted by mistake"));
+        assert!(!client.get_fact(&first_id).deleted);
+
+        // Deleted facts no longer accept votes
+        assert!(client.try_vote(&Address::generate(&env), &second_id, &true).is_err());
+
+        // IDs stay contiguous, so the next fact gets a fresh ID
+        let third_id = client.submit_fact(&creator, &String::from_str(&env, "Resubmitted fact"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(third_id, second_id + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Auth, InvalidAction)")]
+    fn test_delete_fact_by_non_creator() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        env.mock_all_auths();
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Not yours to delete"), &CATEGORY, &VOTING_PERIOD);
+
+        // Only authorize a non-creator address
+        env.mock_auths(&[MockAuth {
+            address: &other,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "delete_fact",
+                args: (fact_id,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+
+        // Non-creator deleting the fact - should panic
+        client.delete_fact(&fact_id);
+    }
+
+    #[test]
+    fn test_sweep_expired() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let expiring_id = client.submit_fact(&creator, &String::from_str(&env, "Time-sensitive fact"), &CATEGORY, &VOTING_PERIOD);
+        let lasting_id = client.submit_fact(&creator, &String::from_str(&env, "Timeless fact"), &CATEGORY, &VOTING_PERIOD);
+        client.set_fact_expiry(&expiring_id, &5_000);
+        let ids = vec![&env, expiring_id, lasting_id];
+
+        // Nothing expires before its expiry has passed
+        env.ledger().set_timestamp(5_000);
+        client.sweep_expired(&ids);
+        assert_eq!(client.get_fact(&expiring_id).status, FactStatus::Open);
+        assert_eq!(client.get_all_facts().len(), 2);
+
+        env.ledger().set_timestamp(5_001);
+        client.sweep_expired(&ids);
+        assert_eq!(client.get_fact(&expiring_id).status, FactStatus::Expired);
+        assert_eq!(client.get_fact(&lasting_id).status, FactStatus::Open);
+
+        // Expired facts stop taking votes and leave the active listing
+        assert!(client.try_vote(&Address::generate(&env), &expiring_id, &true).is_err());
+        let active = client.get_all_facts();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active.get(0).unwrap().id, lasting_id);
+    }
+
+    #[test]
+    fn test_expiry_keeps_verdicts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let settled_id = client.submit_fact(&creator, &String::from_str(&env, "Settled fact"), &CATEGORY, &VOTING_PERIOD);
+        let debunked_id = client.submit_fact(&creator, &String::from_str(&env, "Debunked fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Expiries must lie ahead
+        assert!(client.try_set_fact_expiry(&settled_id, &1_000).is_err());
+        client.set_fact_expiry(&settled_id, &(VOTING_PERIOD + 2_000));
+
+        client.vote(&Address::generate(&env), &debunked_id, &false);
+        client.vote(&Address::generate(&env), &debunked_id, &false);
+        client.vote(&Address::generate(&env), &settled_id, &true);
+        client.vote(&Address::generate(&env), &settled_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&settled_id);
+        client.finalize_fact(&debunked_id);
+
+        // A settled fact can't be given an expiry, and one set earlier doesn't hide its verdict
+        assert!(client.try_set_fact_expiry(&debunked_id, &(VOTING_PERIOD + 2_000)).is_err());
+        env.ledger().set_timestamp(VOTING_PERIOD + 3_000);
+        client.sweep_expired(&vec![&env, settled_id, debunked_id]);
+        assert_eq!(client.get_fact(&settled_id).status, FactStatus::Verified);
+        assert_eq!(client.get_fact(&debunked_id).status, FactStatus::Debunked);
+        assert_eq!(client.get_all_facts().len(), 2);
+    }
+
+    #[test]
+    fn test_transfer_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let old_owner = Address::generate(&env);
+        let new_owner = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&old_owner, &String::from_str(&env, "Handed-off fact"), &CATEGORY, &VOTING_PERIOD);
+        client.transfer_fact(&fact_id, &new_owner);
+        assert_eq!(client.get_fact(&fact_id).creator, new_owner);
+
+        let delete_invoke = MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "delete_fact",
+            args: (fact_id,).into_val(&env),
+            sub_invokes: &[],
+        };
+
+        // The old owner can no longer delete the fact
+        env.mock_auths(&[MockAuth { address: &old_owner, invoke: &delete_invoke }]);
+        assert!(client.try_delete_fact(&fact_id).is_err());
+
+        // The new owner can
+        env.mock_auths(&[MockAuth { address: &new_owner, invoke: &delete_invoke }]);
+        client.delete_fact(&fact_id);
+        assert!(client.get_fact(&fact_id).deleted);
+    }
+
+    #[test]
+    fn test_flag_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let reporter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_flag_threshold(&2);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Dubious fact"), &CATEGORY, &VOTING_PERIOD);
+        client.flag_fact(&reporter, &fact_id);
+        assert_eq!(env.events().all().len(), 0);
+        assert_eq!(client.get_flag_count(&fact_id), 1);
+
+        // Each reporter flags a fact at most once
+        assert!(client.try_flag_fact(&reporter, &fact_id).is_err());
+        assert_eq!(client.get_flag_count(&fact_id), 1);
+
+        // Reaching the threshold alerts moderators
+        client.flag_fact(&Address::generate(&env), &fact_id);
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("flagged"), fact_id).into_val(&env),
+                    2u32.into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(client.get_flag_count(&fact_id), 2);
+
+        // Flags don't touch the tally
+        assert_eq!(client.get_fact(&fact_id).voters.len(), 0);
+    }
+
+    #[test]
+    fn test_add_comment() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let author1 = Address::generate(&env);
+        let author2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Discussed fact"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_comments(&fact_id).len(), 0);
+
+        let first = String::from_str(&env, "Here is a source backing this up");
+        let second = String::from_str(&env, "That source is outdated");
+        client.add_comment(&author1, &fact_id, &first);
+        client.add_comment(&author2, &fact_id, &second);
+
+        // Comments come back in the order they were added
+        assert_eq!(
+            client.get_comments(&fact_id),
+            vec![&env, (author1.clone(), first), (author2.clone(), second)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Comment limit reached")]
+    fn test_add_comment_over_limit() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let author = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Discussed fact"), &CATEGORY, &VOTING_PERIOD);
+        let text = String::from_str(&env, "Me too");
+        for _ in 0..MAX_COMMENTS {
+            client.add_comment(&author, &fact_id, &text);
+        }
+
+        // One comment past the cap - should panic
+        client.add_comment(&author, &fact_id, &text);
+    }
+
+    #[test]
+    fn test_many_voters() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Popular fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Cast a large number of votes, two true for every false
+        let mut voters = Vec::new(&env);
+        for i in 0..150u32 {
+            let voter = Address::generate(&env);
+            client.vote(&voter, &fact_id, &(i % 3 != 0));
+            voters.push_back(voter);
+        }
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 100);
+        assert_eq!(fact.false_votes, 50);
+        assert_eq!(fact.voters.len(), 150);
+
+        // Double votes are still detected deep into the voter set
+        let repeat_voter = voters.get(120).unwrap();
+        assert!(client.try_vote(&repeat_voter, &fact_id, &true).is_err());
+        assert_eq!(client.get_fact(&fact_id).voters.len(), 150);
+    }
+
+    #[test]
+    fn test_get_voter_choice() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let believer = Address::generate(&env);
+        let skeptic = Address::generate(&env);
+        let abstainer = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Checked fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&believer, &fact_id, &true);
+        client.vote(&skeptic, &fact_id, &false);
+        client.vote_abstain(&abstainer, &fact_id);
+
+        assert_eq!(client.get_voter_choice(&fact_id, &believer), Some(true));
+        assert_eq!(client.get_voter_choice(&fact_id, &skeptic), Some(false));
+        assert_eq!(client.get_voter_choice(&fact_id, &abstainer), None);
+        assert_eq!(client.get_voter_choice(&fact_id, &Address::generate(&env)), None);
+
+        // A changed vote reads back its new direction, a retracted one disappears
+        client.change_vote(&skeptic, &fact_id, &true);
+        assert_eq!(client.get_voter_choice(&fact_id, &skeptic), Some(true));
+        client.retract_vote(&believer, &fact_id);
+        assert_eq!(client.get_voter_choice(&fact_id, &believer), None);
+    }
+
+    #[test]
+    fn test_has_voted() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let abstainer = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Checked fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &fact_id, &true);
+        client.vote_abstain(&abstainer, &fact_id);
+
+        assert!(client.has_voted(&fact_id, &voter));
+        assert!(client.has_voted(&fact_id, &abstainer));
+        assert!(!client.has_voted(&fact_id, &Address::generate(&env)));
+
+        // Anonymous votes are found through their nullifier
+        let anonymous_id = client.submit_anonymous_fact(&creator, &String::from_str(&env, "Anonymous fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter, &anonymous_id, &false);
+        assert!(client.has_voted(&anonymous_id, &voter));
+        assert!(!client.has_voted(&anonymous_id, &abstainer));
+
+        // A nonexistent fact has no voters
+        assert!(!client.has_voted(&99, &voter));
+    }
+
+    #[test]
+    fn test_vote_reasons() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let voter3 = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Questioned fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote_with_reason(&voter1, &fact_id, &false, &symbol_short!("outdated"));
+        client.vote_with_reason(&voter2, &fact_id, &false, &symbol_short!("fabricate"));
+        client.vote(&voter3, &fact_id, &true);
+        assert_eq!(client.get_fact(&fact_id).false_votes, 2);
+
+        // Only votes cast with a reason are listed
+        let reasons = client.get_vote_reasons(&fact_id);
+        assert_eq!(reasons.len(), 2);
+        assert!(reasons.contains(&(voter1.clone(), symbol_short!("outdated"))));
+        assert!(reasons.contains(&(voter2.clone(), symbol_short!("fabricate"))));
+
+        // Retracting a vote drops its reason
+        client.retract_vote(&voter1, &fact_id);
+        assert_eq!(client.get_vote_reasons(&fact_id), vec![&env, (voter2, symbol_short!("fabricate"))]);
+    }
+
+    #[test]
+    fn test_get_voters_by_direction() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let believer1 = Address::generate(&env);
+        let believer2 = Address::generate(&env);
+        let skeptic = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Directional fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&believer1, &fact_id, &true);
+        client.vote(&skeptic, &fact_id, &false);
+        client.vote(&believer2, &fact_id, &true);
+
+        let believers = client.get_voters_by_direction(&fact_id, &true);
+        assert_eq!(believers.len(), 2);
+        assert!(believers.contains(&believer1));
+        assert!(believers.contains(&believer2));
+        assert_eq!(client.get_voters_by_direction(&fact_id, &false), vec![&env, skeptic]);
+
+        // A fact without votes in a direction has nobody to list
+        let quiet_id = client.submit_fact(&creator, &String::from_str(&env, "Quiet fact"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_voters_by_direction(&quiet_id, &false).len(), 0);
+    }
+
+    #[test]
+    fn test_get_fact_summary_and_voter_count() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Summarised fact"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_voter_count(&fact_id), 0);
+
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        // The summary mirrors the full fact's scalar fields
+        let fact = client.get_fact(&fact_id);
+        let summary = client.get_fact_summary(&fact_id);
+        assert_eq!(summary.id, fact.id);
+        assert_eq!(summary.text, fact.text);
+        assert_eq!(summary.true_votes, fact.true_votes);
+        assert_eq!(summary.false_votes, fact.false_votes);
+        assert_eq!(summary.status, fact.status);
+
+        assert_eq!(client.get_voter_count(&fact_id), fact.voters.len());
+        assert_eq!(client.get_voter_count(&fact_id), 3);
+    }
+
+    #[test]
+    fn test_finalize_callback() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+        let listener_id = env.register(ListenerContract, ());
+        let listener = ListenerContractClient::new(&env, &listener_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // Without a callback finalization is unaffected
+        let unheard_id = client.submit_fact(&creator, &String::from_str(&env, "Unheard fact"), &CATEGORY, &VOTING_PERIOD);
+        end_voting(&env);
+        client.finalize_fact(&unheard_id);
+
+        client.set_finalize_callback(&listener_id, &symbol_short!("on_final"), &false);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Announced fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&fact_id);
+        assert_eq!(listener.seen(), vec![&env, (fact_id, FactStatus::Verified)]);
+
+        // Clearing the callback stops the notifications
+        client.clear_finalize_callback();
+        let quiet_id = client.submit_fact(&creator, &String::from_str(&env, "Quiet fact"), &CATEGORY, &VOTING_PERIOD);
+        end_voting(&env);
+        client.finalize_fact(&quiet_id);
+        assert_eq!(listener.seen().len(), 1);
+    }
+
+    #[test]
+    fn test_finalize_callback_failure() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+        let listener_id = env.register(ListenerContract, ());
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Announced fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // A failing callback reverts the finalization by default
+        client.set_finalize_callback(&listener_id, &symbol_short!("on_fail"), &false);
+        end_voting(&env);
+        assert!(client.try_finalize_fact(&fact_id).is_err());
+        assert_eq!(client.get_fact(&fact_id).status, FactStatus::Open);
+
+        // Once tolerated, the fact finalizes anyway
+        client.set_finalize_callback(&listener_id, &symbol_short!("on_fail"), &true);
+        assert_eq!(client.finalize_fact(&fact_id), FactStatus::Disputed);
+    }
+
+    #[test]
+    fn test_is_verified_cross_contract() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+        let payout = PayoutContractClient::new(&env, &env.register(PayoutContract, ()));
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let verified_id = client.submit_fact(&creator, &String::from_str(&env, "Insured fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &verified_id, &true);
+        client.vote(&Address::generate(&env), &verified_id, &true);
+        let open_id = client.submit_fact(&creator, &String::from_str(&env, "Pending fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Open facts don't pay out until they're verified
+        assert!(!payout.pays_out(&contract_id, &verified_id));
+        end_voting(&env);
+        client.finalize_fact(&verified_id);
+        assert!(payout.pays_out(&contract_id, &verified_id));
+
+        assert!(!payout.pays_out(&contract_id, &open_id));
+        assert!(!payout.pays_out(&contract_id, &99));
+    }
+
+    #[test]
+    fn test_get_fact_or_none() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Maybe-read fact"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_fact_or_none(&fact_id), Some(client.get_fact(&fact_id)));
+
+        // Never-created IDs read as None instead of panicking
+        assert_eq!(client.get_fact_or_none(&(fact_id + 1)), None);
+    }
+
+    #[test]
+    fn test_get_least_voted_facts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Facts 1 to 5 receive 3, 0, 2, 0 and 1 votes
+        for (n, votes) in [3u32, 0, 2, 0, 1].into_iter().enumerate() {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Review fact", n as u32), &CATEGORY, &VOTING_PERIOD);
+            for _ in 0..votes {
+                client.vote(&Address::generate(&env), &fact_id, &true);
+            }
+        }
+
+        let ids = |count: u32| {
+            let mut ids = Vec::new(&env);
+            for fact in client.get_least_voted_facts(&count).iter() {
+                ids.push_back(fact.id);
+            }
+            ids
+        };
+
+        // Fewest votes first, with ties going to the lower ID
+        assert_eq!(ids(3), vec![&env, 2u32, 4, 5]);
+        assert_eq!(ids(10), vec![&env, 2u32, 4, 5, 3, 1]);
+        assert_eq!(ids(0).len(), 0);
+    }
+
+    #[test]
+    fn test_get_recent_facts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        for n in 0..5 {
+            client.submit_fact(&creator, &numbered_text(&env, "Sequential fact", n), &CATEGORY, &VOTING_PERIOD);
+        }
+
+        let ids = |count: u32| {
+            let mut ids = Vec::new(&env);
+            for fact in client.get_recent_facts(&count).iter() {
+                ids.push_back(fact.id);
+            }
+            ids
+        };
+
+        // The latest facts come first
+        assert_eq!(ids(3), vec![&env, 5, 4, 3]);
+
+        // Asking for more than exist returns everything
+        assert_eq!(ids(10), vec![&env, 5, 4, 3, 2, 1]);
+
+        // Deleted facts are skipped
+        client.delete_fact(&4);
+        assert_eq!(ids(3), vec![&env, 5, 3]);
+    }
+
+    #[test]
+    fn test_get_facts_ranked() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // An old fact with many votes
+        let old_id = client.submit_fact(&creator, &String::from_str(&env, "Old popular fact"), &CATEGORY, &(VOTING_PERIOD * 10));
+        for _ in 0..5 {
+            client.vote(&Address::generate(&env), &old_id, &true);
+        }
+
+        // A day later, a fresh fact with a couple of votes and one with none
+        env.ledger().set_timestamp(VOTING_PERIOD);
+        let fresh_id = client.submit_fact(&creator, &String::from_str(&env, "Fresh trending fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fresh_id, &true);
+        client.vote(&Address::generate(&env), &fresh_id, &false);
+        let quiet_id = client.submit_fact(&creator, &String::from_str(&env, "Fresh quiet fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Recent activity outweighs the old fact's larger tally
+        let ranked = client.get_facts_ranked(&(VOTING_PERIOD + 3600));
+        assert_eq!(
+            ranked,
+            vec![
+                &env,
+                (fresh_id, 3 * RANK_SCALE / 3),
+                (quiet_id, RANK_SCALE / 3),
+                (old_id, 6 * RANK_SCALE / 27),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_result() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let finalize = |true_votes: u32, false_votes: u32| {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Finalized fact", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            for _ in 0..true_votes {
+                client.vote(&Address::generate(&env), &fact_id, &true);
+            }
+            for _ in 0..false_votes {
+                client.vote(&Address::generate(&env), &fact_id, &false);
+            }
+            end_voting(&env);
+            client.finalize_fact(&fact_id);
+            client.get_result(&fact_id)
+        };
+
+        // Decisive verdicts report how far ahead the winning side was
+        assert_eq!(finalize(5, 1), (FactStatus::Verified, 4));
+        assert_eq!(finalize(1, 4), (FactStatus::Debunked, 3));
+
+        // A tie is disputed with no margin
+        assert_eq!(finalize(2, 2), (FactStatus::Disputed, 0));
+    }
+
+    #[test]
+    fn test_get_tallies() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
+        let third_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 3"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &first_id, &true);
+        client.vote(&Address::generate(&env), &second_id, &false);
+        client.vote(&Address::generate(&env), &second_id, &true);
+
+        // The nonexistent ID is omitted
+        let tallies = client.get_tallies(&vec![&env, third_id, 99, first_id, second_id]);
+        assert_eq!(
+            tallies,
+            vec![&env, (third_id, 0, 0), (first_id, 1, 0), (second_id, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_get_top_creators() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let prolific = Address::generate(&env);
+        let occasional = Address::generate(&env);
+        let unverified = Address::generate(&env);
+        env.mock_all_auths();
+
+        let submit_verified = |creator: &Address| {
+            let fact_id = client.submit_fact(creator, &numbered_text(&env, "Verified fact", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            client.vote(&Address::generate(&env), &fact_id, &true);
+            client.vote(&Address::generate(&env), &fact_id, &true);
+            end_voting(&env);
+            client.finalize_fact(&fact_id);
+        };
+        submit_verified(&occasional);
+        submit_verified(&prolific);
+        submit_verified(&prolific);
+        submit_verified(&prolific);
+
+        // Open facts don't count towards the leaderboard
+        client.submit_fact(&unverified, &String::from_str(&env, "Unfinalized fact"), &CATEGORY, &VOTING_PERIOD);
+
+        assert_eq!(
+            client.get_top_creators(&10),
+            vec![&env, (prolific.clone(), 3), (occasional.clone(), 1)]
+        );
+
+        // The leaderboard is truncated to the limit
+        assert_eq!(client.get_top_creators(&1), vec![&env, (prolific.clone(), 3)]);
+    }
+
+    #[test]
+    fn test_get_consensus() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let empty_id = client.submit_fact(&creator, &String::from_str(&env, "No votes yet"), &CATEGORY, &VOTING_PERIOD);
+        let unanimous_id = client.submit_fact(&creator, &String::from_str(&env, "Everyone agrees"), &CATEGORY, &VOTING_PERIOD);
+        let split_id = client.submit_fact(&creator, &String::from_str(&env, "Mostly agreed"), &CATEGORY, &VOTING_PERIOD);
+
+        client.vote(&Address::generate(&env), &unanimous_id, &true);
+        client.vote(&Address::generate(&env), &unanimous_id, &true);
+
+        client.vote(&Address::generate(&env), &split_id, &true);
+        client.vote(&Address::generate(&env), &split_id, &true);
+        client.vote(&Address::generate(&env), &split_id, &true);
+        client.vote(&Address::generate(&env), &split_id, &false);
+
+        assert_eq!(client.get_consensus(&empty_id), (0, 0));
+        assert_eq!(client.get_consensus(&unanimous_id), (100, 2));
+        assert_eq!(client.get_consensus(&split_id), (75, 4));
+    }
+
+    #[test]
+    fn test_get_tally_by_tier() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let newcomer1 = Address::generate(&env);
+        let newcomer2 = Address::generate(&env);
+        let regular = Address::generate(&env);
+        let expert1 = Address::generate(&env);
+        let expert2 = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Give voters reputation across the tiers
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            storage.set(&(REPUTATION_PREFIX, regular.clone()), &1);
+            storage.set(&(REPUTATION_PREFIX, expert1.clone()), &EXPERT_REPUTATION);
+            storage.set(&(REPUTATION_PREFIX, expert2.clone()), &(EXPERT_REPUTATION + 3));
+        });
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Tiered fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&newcomer1, &fact_id, &true);
+        client.vote(&newcomer2, &fact_id, &false);
+        client.vote(&regular, &fact_id, &true);
+        client.vote(&expert1, &fact_id, &false);
+        client.vote(&expert2, &fact_id, &false);
+
+        assert_eq!(
+            client.get_tally_by_tier(&fact_id),
+            vec![
+                &env,
+                (Symbol::new(&env, "new"), 1, 1),
+                (Symbol::new(&env, "established"), 1, 0),
+                (Symbol::new(&env, "expert"), 0, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_tally_since() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.ledger().set_timestamp(1_000);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Trending fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        env.ledger().set_timestamp(5_000);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+
+        env.ledger().set_timestamp(9_000);
+        client.vote(&Address::generate(&env), &fact_id, &true);
+        client.vote(&Address::generate(&env), &fact_id, &false);
+
+        assert_eq!(client.get_tally_since(&fact_id, &0), (3, 2));
+        assert_eq!(client.get_tally_since(&fact_id, &5_000), (2, 1));
+        assert_eq!(client.get_tally_since(&fact_id, &5_001), (1, 1));
+        assert_eq!(client.get_tally_since(&fact_id, &9_001), (0, 0));
+
+        // Votes count with the weight they were applied at
+        let veteran = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&(REPUTATION_PREFIX, veteran.clone()), &2);
+        });
+        env.ledger().set_timestamp(10_000);
+        client.vote(&veteran, &fact_id, &true);
+        assert_eq!(client.get_tally_since(&fact_id, &10_000), (3, 0));
+
+        // A changed vote counts in its new direction from when it changed
+        let changer = Address::generate(&env);
+        client.vote(&changer, &fact_id, &true);
+        env.ledger().set_timestamp(11_000);
+        client.change_vote(&changer, &fact_id, &false);
+        assert_eq!(client.get_tally_since(&fact_id, &10_000), (3, 1));
+        assert_eq!(client.get_tally_since(&fact_id, &11_000), (0, 1));
+
+        // Retracted votes drop out
+        client.retract_vote(&veteran, &fact_id);
+        assert_eq!(client.get_tally_since(&fact_id, &0), (3, 3));
+    }
+
+    #[test]
+    fn test_get_controversy() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let tallied = |true_votes: u32, false_votes: u32| {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Contested fact", client.get_fact_count()), &CATEGORY, &VOTING_PERIOD);
+            for _ in 0..true_votes {
+                client.vote(&Address::generate(&env), &fact_id, &true);
+            }
+            for _ in 0..false_votes {
+                client.vote(&Address::generate(&env), &fact_id, &false);
+            }
+            client.get_controversy(&fact_id)
+        };
+
+        let even = tallied(50, 50);
+        let lopsided = tallied(90, 10);
+        let sparse = tallied(1, 1);
+        assert_eq!(even, 100);
+
+        // Even splits beat lopsided ones, and large splits beat sparse ones
+        assert!(even > lopsided);
+        assert!(lopsided > sparse);
+        assert_eq!(tallied(0, 0), 0);
+    }
+
+    #[test]
+    fn test_poll_voting() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let options = vec![
+            &env,
+            String::from_str(&env, "1969"),
+            String::from_str(&env, "1971"),
+            String::from_str(&env, "1972"),
+        ];
+        let poll_id = client.submit_poll(
+            &creator,
+            &String::from_str(&env, "Which year was the first Moon landing?"),
+            &options,
+        );
+        assert_eq!(poll_id, 1);
+
+        // Voters spread across the options
+        client.vote_poll(&Address::generate(&env), &poll_id, &0);
+        client.vote_poll(&Address::generate(&env), &poll_id, &0);
+        client.vote_poll(&Address::generate(&env), &poll_id, &0);
+        client.vote_poll(&Address::generate(&env), &poll_id, &2);
+
+        let poll = client.get_poll(&poll_id);
+        assert_eq!(poll.options, options);
+        assert_eq!(poll.tallies, vec![&env, 3u32, 0, 1]);
+        assert_eq!(poll.voters.len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already voted on this poll")]
+    fn test_poll_prevent_double_voting() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let options = vec![
+            &env,
+            String::from_str(&env, "Red"),
+            String::from_str(&env, "Green"),
+            String::from_str(&env, "Blue"),
+        ];
+        let poll_id = client.submit_poll(&creator, &String::from_str(&env, "Which colour?"), &options);
+
+        client.vote_poll(&voter, &poll_id, &1);
+
+        // Second vote from same address - should panic
+        client.vote_poll(&voter, &poll_id, &2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid option")]
+    fn test_poll_invalid_option() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let options = vec![&env, String::from_str(&env, "Yes"), String::from_str(&env, "No")];
+        let poll_id = client.submit_poll(&creator, &String::from_str(&env, "Is it raining?"), &options);
+
+        // Out of range option - should panic
+        client.vote_poll(&Address::generate(&env), &poll_id, &2);
+    }
+
+    #[test]
+    fn test_admin_remove_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Spam spam spam"), &CATEGORY, &VOTING_PERIOD);
+
+        client.admin_remove_fact(&fact_id);
+
+        // The removal is recorded as a moderation event
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("remove")).into_val(&env),
+                    (admin.clone(), fact_id).into_val(&env),
+                ),
+            ]
+        );
+
+        assert_eq!(client.get_all_facts().len(), 0);
+        assert!(client.try_get_fact(&fact_id).is_err());
+    }
+
+    #[test]
+    fn test_admin_remove_fact_refunds_votes() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        env.mock_all_auths();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &stake_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &stake_token.address());
+        token_admin.mint(&staker, &100);
+        token_admin.mint(&depositor, &100);
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+        client.set_vote_deposit(&10);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Fact removed with votes"), &CATEGORY, &VOTING_PERIOD);
+        client.vote_with_stake(&staker, &fact_id, &true, &50);
+        client.vote(&depositor, &fact_id, &false);
+        assert_eq!(token_client.balance(&contract_id), 60);
+
+        client.admin_remove_fact(&fact_id);
+
+        // Stakes and deposits go back, and the votes leave every record
+        assert_eq!(token_client.balance(&staker), 100);
+        assert_eq!(token_client.balance(&depositor), 100);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        assert_eq!(client.get_voter_history(&staker).len(), 0);
+        assert_eq!(client.get_voter_history(&depositor).len(), 0);
+        assert_eq!(client.get_total_votes(), 0);
+    }
+
+    #[test]
+    fn test_merge_facts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let both_true = Address::generate(&env);
+        let both_split = Address::generate(&env);
+        let keep_only = Address::generate(&env);
+        let merge_true = Address::generate(&env);
+        let merge_false = Address::generate(&env);
+        let merge_abstain = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let keep_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C"), &CATEGORY, &VOTING_PERIOD);
+        let merge_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100 C at sea level"), &CATEGORY, &VOTING_PERIOD);
+
+        client.vote(&both_true, &keep_id, &true);
+        client.vote(&both_split, &keep_id, &true);
+        client.vote(&keep_only, &keep_id, &false);
+        client.vote(&both_true, &merge_id, &true);
+        client.vote(&both_split, &merge_id, &false);
+        client.vote(&merge_true, &merge_id, &true);
+        client.vote(&merge_false, &merge_id, &false);
+        client.vote_abstain(&merge_abstain, &merge_id);
+        let total_votes = client.get_total_votes();
+
+        client.merge_facts(&keep_id, &merge_id);
+
+        // Overlapping voters keep their original vote and count once
+        let keep = client.get_fact(&keep_id);
+        assert_eq!(keep.true_votes, 3);
+        assert_eq!(keep.false_votes, 2);
+        assert_eq!(keep.abstain_votes, 1);
+        assert_eq!(keep.voters.len(), 6);
+        assert_eq!(client.get_voters_by_direction(&keep_id, &true).len(), 3);
+        assert!(client.has_voted(&keep_id, &merge_abstain));
+        assert_eq!(client.get_voter_history(&merge_true), vec![&env, keep_id]);
+        assert_eq!(client.get_voter_history(&both_true), vec![&env, keep_id]);
+
+        // The duplicate is gone from listings and counters
+        assert!(client.get_fact(&merge_id).deleted);
+        assert_eq!(client.get_all_facts().len(), 1);
+        assert_eq!(client.get_category_count(&CATEGORY), 1);
+        assert_eq!(client.get_status_counts(), (1, 0, 0, 0, 0));
+
+        // Merged voters can't vote again on the kept fact
+        assert!(client.try_vote(&merge_true, &keep_id, &false).is_err());
+
+        // Moved votes show up in the kept fact's timeline
+        assert_eq!(client.get_tally_since(&keep_id, &0), (3, 2));
+
+        // The duplicate votes are counted once, even after the merged fact is removed
+        assert_eq!(client.get_total_votes(), total_votes - 2);
+        client.admin_remove_fact(&merge_id);
+        assert_eq!(client.get_total_votes(), total_votes - 2);
+    }
+
+    #[test]
+    fn test_merge_staked_facts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let staker = Address::generate(&env);
+        let shared = Address::generate(&env);
+        env.mock_all_auths();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_client = token::Client::new(&env, &stake_token.address());
+        let token_admin = token::StellarAssetClient::new(&env, &stake_token.address());
+        token_admin.mint(&staker, &1_000);
+        token_admin.mint(&shared, &1_000);
+
+        client.initialize(&admin);
+        client.set_token(&stake_token.address());
+        client.set_auto_verify_threshold(&300);
+        let keep_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C"), &CATEGORY, &VOTING_PERIOD);
+        let merge_id = client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100 C at sea level"), &CATEGORY, &VOTING_PERIOD);
+
+        client.vote_with_stake(&shared, &keep_id, &true, &150);
+        client.vote_with_stake(&staker, &merge_id, &true, &200);
+        client.vote_with_stake(&shared, &merge_id, &false, &100);
+
+        client.merge_facts(&keep_id, &merge_id);
+
+        // The carried-over stake pushes the kept fact past the auto-verify threshold
+        let keep = client.get_fact(&keep_id);
+        assert_eq!(keep.true_votes, 350);
+        assert_eq!(keep.status, FactStatus::Verified);
+
+        // A stake on a vote that didn't move comes straight back
+        assert_eq!(token_client.balance(&shared), 850);
+
+        // The moved stake is claimed from the kept fact, not the duplicate
+        assert!(client.try_claim_stake(&staker, &merge_id).is_err());
+        client.claim_stake(&staker, &keep_id);
+        client.claim_stake(&shared, &keep_id);
+        assert_eq!(token_client.balance(&staker), 1_000);
+        assert_eq!(token_client.balance(&shared), 1_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot merge a fact into itself")]
+    fn test_merge_fact_into_itself() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&Address::generate(&env), &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        client.merge_facts(&fact_id, &fact_id);
+    }
+
+    #[test]
+    fn test_admin_reset_votes() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        let abstainer = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Brigaded fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &fact_id, &true);
+        client.vote(&voter2, &fact_id, &false);
+        client.vote_abstain(&abstainer, &fact_id);
+
+        client.admin_reset_votes(&fact_id);
+
+        // The reset is recorded as a moderation event
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("reset")).into_val(&env),
+                    (admin.clone(), fact_id).into_val(&env),
+                ),
+            ]
+        );
+
+        let fact = client.get_fact(&fact_id);
+        assert_eq!(fact.true_votes, 0);
+        assert_eq!(fact.false_votes, 0);
+        assert_eq!(fact.voters.len(), 0);
+        assert_eq!(client.get_total_votes(), 0);
+        assert_eq!(client.get_voter_history(&voter1).len(), 0);
+
+        // Prior voters and abstainers can vote again
+        client.vote(&voter1, &fact_id, &false);
+        client.vote(&abstainer, &fact_id, &true);
+        assert_eq!(client.get_fact(&fact_id).false_votes, 1);
+        assert_eq!(client.get_fact(&fact_id).true_votes, 1);
+
+        // Anonymous voters are let back in too
+        let anonymous_id = client.submit_anonymous_fact(&creator, &String::from_str(&env, "Brigaded anonymous fact"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&voter1, &anonymous_id, &true);
+        client.admin_reset_votes(&anonymous_id);
+        client.vote(&voter1, &anonymous_id, &false);
+        assert_eq!(client.get_fact(&anonymous_id).false_votes, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Auth, InvalidAction)")]
+    fn test_admin_remove_fact_by_non_admin() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Legit fact"), &CATEGORY, &VOTING_PERIOD);
+
+        // Only authorize the creator, not the admin
+        env.mock_auths(&[MockAuth {
+            address: &creator,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "admin_remove_fact",
+                args: (fact_id,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+
+        // Non-admin removing the fact - should panic
+        client.admin_remove_fact(&fact_id);
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+
+        // The smallest wasm the host accepts: a module holding only the
+        // contractenvmetav0 section, declaring interface version 23
+        let mut wasm = Bytes::from_slice(&env, b"\0asm\x01\0\0\0\0\x1e\x11contractenvmetav0");
+        wasm.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0]);
+        let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
+
+        client.upgrade(&new_wasm_hash);
+
+        // The upgrade is recorded as a contract-wide moderation event
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("mod"), symbol_short!("upgrade")).into_val(&env),
+                    (admin.clone(), 0_u32).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Auth, InvalidAction)")]
+    fn test_upgrade_by_non_admin() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let attacker = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+
+        // Only authorize the attacker, not the admin
+        let new_wasm_hash = BytesN::from_array(&env, &[7; 32]);
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+
+        // Non-admin upgrading the contract - should panic
+        client.upgrade(&new_wasm_hash);
+    }
+
+    #[test]
+    fn test_migrate_legacy_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let current_id = client.submit_fact(&creator, &String::from_str(&env, "Current fact"), &CATEGORY, &VOTING_PERIOD);
+        assert_eq!(client.get_fact(&current_id).schema_version, FACT_SCHEMA_VERSION);
+
+        // Store a fact in the contract's original layout
+        let legacy_id = current_id + 1;
+        let mut legacy: Map<Symbol, Val> = Map::new(&env);
+        legacy.set(Symbol::new(&env, "id"), legacy_id.into_val(&env));
+        legacy.set(Symbol::new(&env, "text"), String::from_str(&env, "Legacy fact").into_val(&env));
+        legacy.set(Symbol::new(&env, "creator"), creator.into_val(&env));
+        legacy.set(Symbol::new(&env, "true_votes"), 1u32.into_val(&env));
+        legacy.set(Symbol::new(&env, "false_votes"), 0u32.into_val(&env));
+        legacy.set(Symbol::new(&env, "voters"), vec![&env, voter.clone()].into_val(&env));
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&(FACT_PREFIX, legacy_id), &legacy);
+            env.storage().instance().set(&FACT_COUNT, &legacy_id);
+        });
+
+        // The old layout can't be read until it's migrated
+        assert!(client.try_get_fact(&legacy_id).is_err());
+        assert_eq!(client.migrate_all(), 1);
+
+        // Existing fields survive and new ones get their defaults
+        let fact = client.get_fact(&legacy_id);
+        assert_eq!(fact.schema_version, FACT_SCHEMA_VERSION);
+        assert_eq!(fact.text, String::from_str(&env, "Legacy fact"));
+        assert_eq!(fact.true_votes, 1);
+        assert_eq!(fact.voters, vec![&env, voter]);
+        assert_eq!(fact.status, FactStatus::Open);
+        assert_eq!(fact.voting_ends_at, u64::MAX);
+        assert_eq!(fact.source, String::from_str(&env, ""));
+        assert_eq!(fact.category, LEGACY_CATEGORY);
+        assert!(!fact.deleted);
+
+        // Current facts are left alone
+        assert!(!client.migrate_fact(&current_id));
+        assert_eq!(client.migrate_all(), 0);
+    }
+
+    #[test]
+    fn test_submit_facts_batch() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.submit_fact(&creator, &String::from_str(&env, "Existing fact"), &CATEGORY, &VOTING_PERIOD);
+
+        let texts = vec![
+            &env,
+            String::from_str(&env, "Batch fact 1"),
+            String::from_str(&env, "Batch fact 2"),
+            String::from_str(&env, "Batch fact 3"),
+            String::from_str(&env, "Batch fact 4"),
+        ];
+        let ids = client.submit_facts_batch(&creator, &texts, &CATEGORY, &VOTING_PERIOD);
+
+        // IDs continue contiguously from the existing counter
+        assert_eq!(ids, vec![&env, 2u32, 3, 4, 5]);
+        assert_eq!(client.get_fact_count(), 5);
+        assert_eq!(client.get_fact(&4).text, String::from_str(&env, "Batch fact 3"));
+    }
+
+    #[test]
+    fn test_submit_facts_batch_cooldown() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_submit_cooldown(&60);
+
+        // A multi-fact batch would get around the cooldown
+        let texts = vec![
+            &env,
+            String::from_str(&env, "Batch fact 1"),
+            String::from_str(&env, "Batch fact 2"),
+        ];
+        assert!(client.try_submit_facts_batch(&creator, &texts, &CATEGORY, &VOTING_PERIOD).is_err());
+
+        // A single fact counts as one submission
+        env.ledger().set_timestamp(1_000);
+        let single = vec![&env, String::from_str(&env, "Batch fact 1")];
+        assert_eq!(client.submit_facts_batch(&creator, &single, &CATEGORY, &VOTING_PERIOD), vec![&env, 1u32]);
+        env.ledger().set_timestamp(1_059);
+        assert!(client.try_submit_facts_batch(&creator, &single, &CATEGORY, &VOTING_PERIOD).is_err());
+    }
+
+    #[test]
+    fn test_get_voter_history() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        for n in 0..4 {
+            client.submit_fact(&creator, &numbered_text(&env, "History fact", n), &CATEGORY, &VOTING_PERIOD);
+        }
+
+        // Vote on three of the four facts
+        client.vote(&voter, &3, &true);
+        client.vote(&voter, &1, &false);
+        client.vote(&voter, &4, &true);
+        client.vote(&Address::generate(&env), &2, &true);
+
+        assert_eq!(client.get_voter_history(&voter), vec![&env, 3u32, 1, 4]);
+
+        // Retracted votes leave the history
+        client.retract_vote(&voter, &1);
+        assert_eq!(client.get_voter_history(&voter), vec![&env, 3u32, 4]);
+
+        // Addresses that never voted have an empty history
+        assert_eq!(client.get_voter_history(&Address::generate(&env)).len(), 0);
+    }
+
+    #[test]
+    fn test_get_vote_count_by() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let active = Address::generate(&env);
+        let casual = Address::generate(&env);
+        env.mock_all_auths();
+
+        for n in 0..3 {
+            let fact_id = client.submit_fact(&creator, &numbered_text(&env, "Counted fact", n), &CATEGORY, &VOTING_PERIOD);
+            client.vote(&active, &fact_id, &true);
+        }
+        client.vote(&casual, &2, &false);
+
+        assert_eq!(client.get_vote_count_by(&active), 3);
+        assert_eq!(client.get_vote_count_by(&casual), 1);
+        assert_eq!(client.get_vote_count_by(&Address::generate(&env)), 0);
+    }
+
+    #[test]
+    fn test_custom_ttl_bump() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        client.set_ttl_bump(&100_000);
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Long-lived fact"), &CATEGORY, &VOTING_PERIOD);
+        let ttl = env.as_contract(&contract_id, || {
+            env.storage().persistent().get_ttl(&(FACT_PREFIX, fact_id))
+        });
+        assert_eq!(ttl, 100_000);
+
+        // Well past the default bump, the fact is still retrievable
+        env.ledger().with_mut(|li| li.sequence_number += DEFAULT_TTL_BUMP + 50_000);
+        assert_eq!(client.get_fact(&fact_id).id, fact_id);
+    }
+
+    #[test]
+    fn test_default_ttl_bump() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Without configuration the default bump applies
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Default fact"), &CATEGORY, &VOTING_PERIOD);
+        let ttl = env.as_contract(&contract_id, || {
+            env.storage().persistent().get_ttl(&(FACT_PREFIX, fact_id))
+        });
+        assert_eq!(ttl, DEFAULT_TTL_BUMP);
+    }
+
+    #[test]
+    fn test_peek_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
+
+        let fact_id = client.submit_fact(&creator, &String::from_str(&env, "Polled fact"), &CATEGORY, &VOTING_PERIOD);
+        let fact_ttl = || env.as_contract(&contract_id, || {
+            env.storage().persistent().get_ttl(&(FACT_PREFIX, fact_id))
+        });
+
+        // Peeking returns the same data but leaves the TTL to run down
+        env.ledger().with_mut(|li| li.sequence_number += 1_000);
+        assert_eq!(client.peek_fact(&fact_id), client.get_fact_or_none(&fact_id).unwrap());
+        assert_eq!(fact_ttl(), DEFAULT_TTL_BUMP - 1_000);
+
+        // A regular read bumps it back up
+        assert_eq!(client.get_fact(&fact_id), client.peek_fact(&fact_id));
+        assert_eq!(fact_ttl(), DEFAULT_TTL_BUMP);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fact not found")]
+    fn test_peek_missing_fact() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
-        // Get the current fact count (or initialize to 0)
-        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
-        
-        // Create new fact ID (starting from 1)
-        let new_id = fact_count + 1;
+        client.peek_fact(&1);
+    }
 
-        // Create the fact struct
-        let fact = Fact {
-            id: new_id,
-            text: text.clone(),
-            creator: creator.clone(),
-            true_votes: 0,
-            false_votes: 0,
-            voters: Vec::new(&env),
-        };
+    #[test]
+    fn test_bump_ttl() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
-        // Store the fact using a composite key
-        let fact_key = (FACT_PREFIX, new_id);
-        env.storage().persistent().set(&fact_key, &fact);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
 
-        // Update the fact count
-        env.storage().instance().set(&FACT_COUNT, &new_id);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Curated fact 1"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Curated fact 2"), &CATEGORY, &VOTING_PERIOD);
 
-        // Extend the TTL for the fact and counter
-        env.storage().persistent().extend_ttl(&fact_key, 100, 100);
-        env.storage().instance().extend_ttl(100, 100);
+        // Close to expiry, refresh both facts (and skip a missing ID)
+        env.ledger().with_mut(|li| li.sequence_number += DEFAULT_TTL_BUMP - 100);
+        client.bump_ttl(&vec![&env, first_id, second_id, 99]);
+        let ttl = env.as_contract(&contract_id, || {
+            env.storage().persistent().get_ttl(&(FACT_PREFIX, first_id))
+        });
+        assert_eq!(ttl, DEFAULT_TTL_BUMP);
 
-        new_id
+        // Past the original expiry, the facts are still retrievable
+        env.ledger().with_mut(|li| li.sequence_number += 1_000);
+        assert_eq!(client.get_fact(&first_id).id, first_id);
+        assert_eq!(client.get_fact(&second_id).id, second_id);
     }
 
-    /// Vote on a fact (true or false)
-    /// 
-    /// # Arguments
-    /// * env - The Soroban environment
-    /// * voter - The address of the voter
-    /// * fact_id - The ID of the fact to vote on
-    /// * is_true - True for "true" vote, false for "false" vote
-    /// 
-    /// # Panics
-    /// * If the fact doesn't exist
-    /// * If the voter has already voted on this fact
-    pub fn vote(env: Env, voter: Address, fact_id: u32, is_true: bool) {
-        // Require authorization from the voter
-        voter.require_auth();
-
-        // Retrieve the fact
-        let fact_key = (FACT_PREFIX, fact_id);
-        let mut fact: Fact = env.storage()
-            .persistent()
-            .get(&fact_key)
-            .expect("Fact not found");
+    #[test]
+    fn test_get_all_facts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
-        // Check if voter has already voted
-        for existing_voter in fact.voters.iter() {
-            if existing_voter == voter {
-                panic!("Already voted on this fact");
-            }
-        }
+        let creator = Address::generate(&env);
+        let fact1 = String::from_str(&env, "Sample fact 1");
+        let fact2 = String::from_str(&env, "Sample fact 2");
+        let fact3 = String::from_str(&env, "Sample fact 3");
 
-        // Add voter to the list
-        fact.voters.push_back(voter.clone());
+        env.mock_all_auths();
 
-        // Increment the appropriate vote counter
-        if is_true {
-            fact.true_votes += 1;
-        } else {
-            fact.false_votes += 1;
-        }
+        // Submit multiple facts
+        client.submit_fact(&creator, &fact1, &CATEGORY, &VOTING_PERIOD);
+        client.submit_fact(&creator, &fact2, &CATEGORY, &VOTING_PERIOD);
+        client.submit_fact(&creator, &fact3, &CATEGORY, &VOTING_PERIOD);
 
-        // Save the updated fact
-        env.storage().persistent().set(&fact_key, &fact);
-        
-        // Extend TTL
-        env.storage().persistent().extend_ttl(&fact_key, 100, 100);
+        // Get all facts
+        let all_facts = client.get_all_facts();
+        assert_eq!(all_facts.len(), 3);
+        assert_eq!(all_facts.get(0).unwrap().text, fact1);
+        assert_eq!(all_facts.get(1).unwrap().text, fact2);
+        assert_eq!(all_facts.get(2).unwrap().text, fact3);
     }
 
-    /// Get details of a specific fact
-    /// 
-    /// # Arguments
-    /// * env - The Soroban environment
-    /// * fact_id - The ID of the fact to retrieve
-    /// 
-    /// # Returns
-    /// * Fact - The fact details
-    /// 
-    /// # Panics
-    /// * If the fact doesn't exist
-    pub fn get_fact(env: Env, fact_id: u32) -> Fact {
-        let fact_key = (FACT_PREFIX, fact_id);
-        let fact: Fact = env.storage()
-            .persistent()
-            .get(&fact_key)
-            .expect("Fact not found");
-        
-        // Extend TTL on read
-        env.storage().persistent().extend_ttl(&fact_key, 100, 100);
-        
-        fact
-    }
+    #[test]
+    fn test_get_facts_paged() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
-    /// Get all facts stored in the contract
-    /// 
-    /// # Arguments
-    /// * env - The Soroban environment
-    /// 
-    /// # Returns
-    /// * Vec<Fact> - A vector containing all facts
-    pub fn get_all_facts(env: Env) -> Vec<Fact> {
-        let fact_count: u32 = env.storage().instance().get(&FACT_COUNT).unwrap_or(0);
-        let mut facts = Vec::new(&env);
+        let creator = Address::generate(&env);
+        env.mock_all_auths();
 
-        // Iterate through all fact IDs and collect them
-        for id in 1..=fact_count {
-            let fact_key = (FACT_PREFIX, id);
-            if let Some(fact) = env.storage().persistent().get::<_, Fact>(&fact_key) {
-                facts.push_back(fact);
-                // Extend TTL
-                env.storage().persistent().extend_ttl(&fact_key, 100, 100);
-            }
+        // Submit 10 facts
+        for n in 0..10 {
+            client.submit_fact(&creator, &numbered_text(&env, "Paged fact", n), &CATEGORY, &VOTING_PERIOD);
         }
 
-        facts
-    }
+        // Request a page from the middle
+        let page = client.get_facts_paged(&4, &3);
+        assert_eq!(page.len(), 3);
+        assert_eq!(page.get(0).unwrap().id, 4);
+        assert_eq!(page.get(1).unwrap().id, 5);
+        assert_eq!(page.get(2).unwrap().id, 6);
 
-    /// Get the total number of facts
-    /// 
-    /// # Arguments
-    /// * env - The Soroban environment
-    /// 
-    /// # Returns
-    /// * u32 - The total count of facts
-    pub fn get_fact_count(env: Env) -> u32 {
-        env.storage().instance().get(&FACT_COUNT).unwrap_or(0)
+        // A page running past the end is truncated
+        let page = client.get_facts_paged(&9, &5);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap().id, 9);
+        assert_eq!(page.get(1).unwrap().id, 10);
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
 
     #[test]
-    fn test_submit_fact() {
+    fn test_get_facts_by_category() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
         let creator = Address::generate(&env);
-        let fact_text = String::from_str(&env, "The Earth is round");
-
-        // Mock the authorization
+        let science = symbol_short!("science");
+        let politics = symbol_short!("politics");
         env.mock_all_auths();
 
-        // Submit a fact
-        let fact_id = client.submit_fact(&creator, &fact_text);
+        client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &science, &VOTING_PERIOD);
+        client.submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &politics, &VOTING_PERIOD);
+        client.submit_fact(&creator, &String::from_str(&env, "Sample fact 3"), &science, &VOTING_PERIOD);
 
-        assert_eq!(fact_id, 1);
+        let science_facts = client.get_facts_by_category(&science);
+        assert_eq!(science_facts.len(), 2);
+        assert_eq!(science_facts.get(0).unwrap().id, 1);
+        assert_eq!(science_facts.get(1).unwrap().id, 3);
 
-        // Verify the fact was stored correctly
-        let fact = client.get_fact(&fact_id);
-        assert_eq!(fact.id, 1);
-        assert_eq!(fact.text, fact_text);
-        assert_eq!(fact.creator, creator);
-        assert_eq!(fact.true_votes, 0);
-        assert_eq!(fact.false_votes, 0);
-        assert_eq!(fact.voters.len(), 0);
+        let politics_facts = client.get_facts_by_category(&politics);
+        assert_eq!(politics_facts.len(), 1);
+        assert_eq!(politics_facts.get(0).unwrap().id, 2);
+
+        // Unknown categories return an empty list
+        assert_eq!(client.get_facts_by_category(&symbol_short!("sports")).len(), 0);
     }
 
     #[test]
-    fn test_vote_true() {
+    fn test_get_facts_by_status() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
         let creator = Address::generate(&env);
-        let voter = Address::generate(&env);
-        let fact_text = String::from_str(&env, "Water freezes at 0°C");
+        env.mock_all_auths();
+
+        // No facts yet, so every filter is empty
+        assert_eq!(client.get_facts_by_status(&FactStatus::Verified).len(), 0);
+
+        let verified_id = client.submit_fact(&creator, &String::from_str(&env, "Will be verified"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &verified_id, &true);
+        client.vote(&Address::generate(&env), &verified_id, &true);
+        end_voting(&env);
+        client.finalize_fact(&verified_id);
+
+        let debunked_id = client.submit_fact(&creator, &String::from_str(&env, "Will be debunked"), &CATEGORY, &VOTING_PERIOD);
+        client.vote(&Address::generate(&env), &debunked_id, &false);
+        client.vote(&Address::generate(&env), &debunked_id, &false);
+        end_voting(&env);
+        client.finalize_fact(&debunked_id);
+
+        let disputed_id = client.submit_fact(&creator, &String::from_str(&env, "Will be disputed"), &CATEGORY, &VOTING_PERIOD);
+        end_voting(&env);
+        client.finalize_fact(&disputed_id);
+
+        let open_id = client.submit_fact(&creator, &String::from_str(&env, "Still open fact"), &CATEGORY, &VOTING_PERIOD);
+
+        let ids = |status: FactStatus| {
+            let mut ids = Vec::new(&env);
+            for fact in client.get_facts_by_status(&status).iter() {
+                ids.push_back(fact.id);
+            }
+            ids
+        };
+        assert_eq!(ids(FactStatus::Verified), vec![&env, verified_id]);
+        assert_eq!(ids(FactStatus::Debunked), vec![&env, debunked_id]);
+        assert_eq!(ids(FactStatus::Disputed), vec![&env, disputed_id]);
+        assert_eq!(ids(FactStatus::Open), vec![&env, open_id]);
+    }
+
+    #[test]
+    fn test_search_facts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
+        let creator = Address::generate(&env);
         env.mock_all_auths();
 
-        // Submit a fact
-        let fact_id = client.submit_fact(&creator, &fact_text);
+        let moon_id = client.submit_fact(&creator, &String::from_str(&env, "The Moon orbits the Earth"), &CATEGORY, &VOTING_PERIOD);
+        client.submit_fact(&creator, &String::from_str(&env, "Water boils at 100C at sea level"), &CATEGORY, &VOTING_PERIOD);
+        let sun_id = client.submit_fact(&creator, &String::from_str(&env, "The Earth orbits the Sun"), &CATEGORY, &VOTING_PERIOD);
 
-        // Vote true
-        client.vote(&voter, &fact_id, &true);
+        let ids = |query: &str| {
+            let mut ids = Vec::new(&env);
+            for fact in client.search_facts(&String::from_str(&env, query)).iter() {
+                ids.push_back(fact.id);
+            }
+            ids
+        };
 
-        // Check the vote was recorded
-        let fact = client.get_fact(&fact_id);
-        assert_eq!(fact.true_votes, 1);
-        assert_eq!(fact.false_votes, 0);
-        assert_eq!(fact.voters.len(), 1);
-        assert_eq!(fact.voters.get(0).unwrap(), voter);
+        // Only facts containing the term are returned
+        assert_eq!(ids("orbits"), vec![&env, moon_id, sun_id]);
+        assert_eq!(ids("Sun"), vec![&env, sun_id]);
+
+        // Matching is case-sensitive
+        assert_eq!(ids("moon").len(), 0);
+
+        // A query longer than any fact matches nothing
+        assert_eq!(ids("The Moon orbits the Earth and the Sun at once").len(), 0);
     }
 
     #[test]
-    fn test_vote_false() {
+    fn test_get_category_count() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
+        let admin = Address::generate(&env);
         let creator = Address::generate(&env);
-        let voter = Address::generate(&env);
-        let fact_text = String::from_str(&env, "The Moon is made of cheese");
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        let science = symbol_short!("science");
+        let history = symbol_short!("history");
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Science fact 1"), &science, &VOTING_PERIOD);
+        client.submit_fact(&creator, &String::from_str(&env, "Science fact 2"), &science, &VOTING_PERIOD);
+        let history_id = client.submit_fact(&creator, &String::from_str(&env, "History fact 1"), &history, &VOTING_PERIOD);
+
+        assert_eq!(client.get_category_count(&science), 2);
+        assert_eq!(client.get_category_count(&history), 1);
+        assert_eq!(client.get_category_count(&symbol_short!("sports")), 0);
+
+        // Deleting takes a fact out of its category, but only once
+        client.delete_fact(&first_id);
+        client.delete_fact(&first_id);
+        assert_eq!(client.get_category_count(&science), 1);
+
+        // So does moderation removal
+        client.admin_remove_fact(&history_id);
+        assert_eq!(client.get_category_count(&history), 0);
+    }
+
+    #[test]
+    fn test_get_status_counts() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
         env.mock_all_auths();
 
-        // Submit a fact
-        let fact_id = client.submit_fact(&creator, &fact_text);
+        client.initialize(&admin);
+        let mut ids = Vec::new(&env);
+        for n in 0..6 {
+            ids.push_back(client.submit_fact(&creator, &numbered_text(&env, "Counted fact", n), &CATEGORY, &VOTING_PERIOD));
+        }
+        assert_eq!(client.get_status_counts(), (6, 0, 0, 0, 0));
+        client.set_fact_expiry(&ids.get(5).unwrap(), &VOTING_PERIOD);
 
-        // Vote false
-        client.vote(&voter, &fact_id, &false);
+        // Settle two facts as Verified, one as Debunked and one as Disputed
+        for (index, is_true) in [(0, true), (1, true), (2, false)] {
+            client.vote(&Address::generate(&env), &ids.get(index).unwrap(), &is_true);
+            client.vote(&Address::generate(&env), &ids.get(index).unwrap(), &is_true);
+        }
+        end_voting(&env);
+        for index in 0..4 {
+            client.finalize_fact(&ids.get(index).unwrap());
+        }
+        assert_eq!(client.get_status_counts(), (2, 2, 1, 1, 0));
 
-        // Check the vote was recorded
-        let fact = client.get_fact(&fact_id);
-        assert_eq!(fact.true_votes, 0);
-        assert_eq!(fact.false_votes, 1);
-        assert_eq!(fact.voters.len(), 1);
+        // A challenge moves a verdict back to Disputed, and deletions drop out
+        client.challenge_fact(&Address::generate(&env), &ids.get(1).unwrap());
+        client.delete_fact(&ids.get(4).unwrap());
+        client.delete_fact(&ids.get(4).unwrap());
+        client.admin_remove_fact(&ids.get(2).unwrap());
+        assert_eq!(client.get_status_counts(), (1, 1, 0, 2, 0));
+
+        // Expired facts are counted separately
+        client.sweep_expired(&vec![&env, ids.get(5).unwrap()]);
+        assert_eq!(client.get_status_counts(), (0, 1, 0, 2, 1));
+
+        // The counters agree with the stored statuses
+        let tally = |status| {
+            ids.iter()
+                .filter_map(|id| client.try_get_fact(&id).ok()?.ok())
+                .filter(|fact| !fact.deleted && fact.status == status)
+                .count() as u32
+        };
+        assert_eq!(
+            client.get_status_counts(),
+            (
+                tally(FactStatus::Open),
+                tally(FactStatus::Verified),
+                tally(FactStatus::Debunked),
+                tally(FactStatus::Disputed),
+                tally(FactStatus::Expired),
+            )
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Already voted on this fact")]
-    fn test_prevent_double_voting() {
+    fn test_get_unvoted_facts() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
         let creator = Address::generate(&env);
         let voter = Address::generate(&env);
-        let fact_text = String::from_str(&env, "Rust is awesome");
-
         env.mock_all_auths();
 
-        // Submit a fact
-        let fact_id = client.submit_fact(&creator, &fact_text);
+        let mut ids = Vec::new(&env);
+        for n in 0..3 {
+            ids.push_back(client.submit_fact(&creator, &numbered_text(&env, "Feed fact", n), &CATEGORY, &VOTING_PERIOD));
+        }
+        // The voter's own fact and a finalized fact never show up
+        client.submit_fact(&voter, &String::from_str(&env, "Voter's own fact"), &CATEGORY, &VOTING_PERIOD);
+        let finalized_id = client.submit_fact(&creator, &String::from_str(&env, "Finalized feed fact"), &CATEGORY, &100);
+        env.ledger().set_timestamp(101);
+        client.finalize_fact(&finalized_id);
 
-        // First vote
-        client.vote(&voter, &fact_id, &true);
+        client.vote(&voter, &ids.get(1).unwrap(), &true);
 
-        // Second vote from same address - should panic
-        client.vote(&voter, &fact_id, &false);
+        let facts = client.get_unvoted_facts(&voter, &10);
+        assert_eq!(facts.len(), 2);
+        assert_eq!(facts.get(0).unwrap().id, ids.get(0).unwrap());
+        assert_eq!(facts.get(1).unwrap().id, ids.get(2).unwrap());
+
+        // The limit caps the result
+        assert_eq!(client.get_unvoted_facts(&voter, &1).len(), 1);
     }
 
     #[test]
-    fn test_multiple_voters() {
+    fn test_get_facts_between() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
         let creator = Address::generate(&env);
-        let voter1 = Address::generate(&env);
-        let voter2 = Address::generate(&env);
-        let voter3 = Address::generate(&env);
-        let fact_text = String::from_str(&env, "Blockchain is decentralized");
+        env.mock_all_auths();
+
+        // Submit a fact at each of four timestamps
+        let mut ids = Vec::new(&env);
+        for (n, timestamp) in [1_000u64, 2_000, 3_000, 4_000].iter().enumerate() {
+            env.ledger().set_timestamp(*timestamp);
+            ids.push_back(client.submit_fact(&creator, &numbered_text(&env, "Timed fact", n as u32), &CATEGORY, &VOTING_PERIOD));
+        }
+
+        // The window is inclusive at both ends
+        let facts = client.get_facts_between(&2_000, &3_000);
+        assert_eq!(facts.len(), 2);
+        assert_eq!(facts.get(0).unwrap().id, ids.get(1).unwrap());
+        assert_eq!(facts.get(1).unwrap().id, ids.get(2).unwrap());
+
+        assert_eq!(client.get_facts_between(&4_001, &5_000).len(), 0);
+    }
+
+    #[test]
+    fn test_get_facts_by_creator() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
 
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
         env.mock_all_auths();
 
-        // Submit a fact
-        let fact_id = client.submit_fact(&creator, &fact_text);
+        client.submit_fact(&alice, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        client.submit_fact(&bob, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
+        client.submit_fact(&alice, &String::from_str(&env, "Sample fact 3"), &CATEGORY, &VOTING_PERIOD);
 
-        // Multiple voters
-        client.vote(&voter1, &fact_id, &true);
-        client.vote(&voter2, &fact_id, &true);
-        client.vote(&voter3, &fact_id, &false);
+        let alice_facts = client.get_facts_by_creator(&alice);
+        assert_eq!(alice_facts.len(), 2);
+        assert_eq!(alice_facts.get(0).unwrap().id, 1);
+        assert_eq!(alice_facts.get(1).unwrap().id, 3);
 
-        // Check votes
-        let fact = client.get_fact(&fact_id);
-        assert_eq!(fact.true_votes, 2);
-        assert_eq!(fact.false_votes, 1);
-        assert_eq!(fact.voters.len(), 3);
+        let bob_facts = client.get_facts_by_creator(&bob);
+        assert_eq!(bob_facts.len(), 1);
+        assert_eq!(bob_facts.get(0).unwrap().id, 2);
     }
 
     #[test]
-    fn test_get_all_facts() {
+    fn test_get_total_votes() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
         let creator = Address::generate(&env);
-        let fact1 = String::from_str(&env, "Fact 1");
-        let fact2 = String::from_str(&env, "Fact 2");
-        let fact3 = String::from_str(&env, "Fact 3");
-
+        let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        // Submit multiple facts
-        client.submit_fact(&creator, &fact1);
-        client.submit_fact(&creator, &fact2);
-        client.submit_fact(&creator, &fact3);
+        assert_eq!(client.get_total_votes(), 0);
 
-        // Get all facts
-        let all_facts = client.get_all_facts();
-        assert_eq!(all_facts.len(), 3);
-        assert_eq!(all_facts.get(0).unwrap().text, fact1);
-        assert_eq!(all_facts.get(1).unwrap().text, fact2);
-        assert_eq!(all_facts.get(2).unwrap().text, fact3);
+        let first_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
+        let second_id = client.submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
+
+        client.vote(&voter, &first_id, &true);
+        client.vote(&Address::generate(&env), &first_id, &false);
+        client.vote(&Address::generate(&env), &first_id, &true);
+        client.vote(&voter, &second_id, &false);
+        client.vote(&Address::generate(&env), &second_id, &false);
+
+        assert_eq!(client.get_total_votes(), 5);
+
+        // Changing a vote keeps the total, retracting one lowers it
+        client.change_vote(&voter, &first_id, &false);
+        assert_eq!(client.get_total_votes(), 5);
+        client.retract_vote(&voter, &second_id);
+        assert_eq!(client.get_total_votes(), 4);
     }
 
     #[test]
     fn test_get_fact_count() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, FactVerificationContract);
+        let contract_id = env.register(FactVerificationContract, ());
         let client = FactVerificationContractClient::new(&env, &contract_id);
 
         let creator = Address::generate(&env);
@@ -345,11 +9862,68 @@ mod test {
         assert_eq!(client.get_fact_count(), 0);
 
         // After one submission
-        client.submit_fact(&creator, &String::from_str(&env, "Fact 1"));
+        client.submit_fact(&creator, &String::from_str(&env, "Sample fact 1"), &CATEGORY, &VOTING_PERIOD);
         assert_eq!(client.get_fact_count(), 1);
 
         // After two submissions
-        client.submit_fact(&creator, &String::from_str(&env, "Fact 2"));
+        client.submit_fact(&creator, &String::from_str(&env, "Sample fact 2"), &CATEGORY, &VOTING_PERIOD);
         assert_eq!(client.get_fact_count(), 2);
     }
+
+    #[test]
+    fn test_get_stats() {
+        let env = Env::default();
+        let contract_id = env.register(FactVerificationContract, ());
+        let client = FactVerificationContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let abstainer = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize(&admin);
+        assert_eq!(client.get_stats(), Stats {
+            total_facts: 0,
+            total_votes: 0,
+            open: 0,
+            verified: 0,
+            debunked: 0,
+            disputed: 0,
+            expired: 0,
+            unique_voters: 0,
+        });
+
+        let mut ids = Vec::new(&env);
+        for n in 0..3 {
+            ids.push_back(client.submit_fact(&creator, &numbered_text(&env, "Stats fact", n), &CATEGORY, &VOTING_PERIOD));
+        }
+
+        // Five votes and an abstention from three addresses
+        for fact_id in ids.iter() {
+            client.vote(&first, &fact_id, &true);
+        }
+        client.vote(&second, &ids.get(0).unwrap(), &true);
+        client.vote(&second, &ids.get(1).unwrap(), &false);
+        client.vote_abstain(&abstainer, &ids.get(2).unwrap());
+
+        // A changed vote doesn't count its voter twice
+        client.change_vote(&second, &ids.get(1).unwrap(), &true);
+        assert_eq!(client.get_stats().unique_voters, 3);
+
+        // Two true votes verify the first fact, no votes leave the others open
+        end_voting(&env);
+        client.finalize_fact(&ids.get(0).unwrap());
+        assert_eq!(client.get_stats(), Stats {
+            total_facts: 3,
+            total_votes: 5,
+            open: 2,
+            verified: 1,
+            debunked: 0,
+            disputed: 0,
+            expired: 0,
+            unique_voters: 3,
+        });
+    }
 }
\ No newline at end of file